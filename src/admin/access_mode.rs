@@ -0,0 +1,117 @@
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use super::Admin;
+use crate::upload::{AccessModes, ResourceTypes};
+
+/// The assets [`Admin::update_access_mode`] applies to.
+#[derive(Debug, Clone)]
+pub enum AccessModeTarget {
+    /// Up to 100 public ids.
+    PublicIds(Vec<String>),
+    /// All assets whose public id starts with the prefix.
+    Prefix(String),
+    /// All assets with the tag.
+    Tag(String),
+}
+
+impl AccessModeTarget {
+    fn body(&self, access_mode: &AccessModes) -> Map<String, Value> {
+        let mut body = Map::new();
+        body.insert("access_mode".into(), access_mode.to_string().into());
+        match self {
+            AccessModeTarget::PublicIds(public_ids) => {
+                body.insert("public_ids".into(), public_ids.clone().into())
+            }
+            AccessModeTarget::Prefix(prefix) => body.insert("prefix".into(), prefix.clone().into()),
+            AccessModeTarget::Tag(tag) => body.insert("tag".into(), tag.clone().into()),
+        };
+        body
+    }
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct AccessModeUpdate {
+    pub public_id: String,
+    /// `updated` on success.
+    pub status: String,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct AccessModeResult {
+    #[serde(default)]
+    pub success: Vec<AccessModeUpdate>,
+    #[serde(default)]
+    pub failed: Vec<AccessModeUpdate>,
+}
+
+impl Admin {
+    /// Changes the access mode of uploaded assets, e.g. to make authenticated assets public without changing their
+    /// delivery URLs.
+    ///
+    /// ```rust
+    /// # async fn launch() {
+    /// use cloudinary::admin::{Admin, access_mode::AccessModeTarget};
+    /// use cloudinary::upload::{AccessModes, ResourceTypes};
+    ///
+    /// let admin = Admin::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string());
+    /// let result = admin
+    ///     .update_access_mode(
+    ///         ResourceTypes::Image,
+    ///         &AccessModeTarget::Tag("spring_collection".into()),
+    ///         AccessModes::Public,
+    ///     )
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn update_access_mode(
+        &self,
+        resource_type: ResourceTypes,
+        target: &AccessModeTarget,
+        access_mode: AccessModes,
+    ) -> Result<AccessModeResult> {
+        self.post(
+            self.endpoint(&[
+                "resources",
+                &resource_type.to_string(),
+                "upload",
+                "update_access_mode",
+            ]),
+            &target.body(&access_mode),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn body() {
+        assert_eq!(
+            Value::from(
+                AccessModeTarget::PublicIds(vec!["a".into(), "b".into()])
+                    .body(&AccessModes::Authenticated)
+            ),
+            serde_json::json!({"access_mode": "authenticated", "public_ids": ["a", "b"]})
+        );
+        assert_eq!(
+            Value::from(AccessModeTarget::Prefix("shoes/".into()).body(&AccessModes::Public)),
+            serde_json::json!({"access_mode": "public", "prefix": "shoes/"})
+        );
+    }
+
+    #[test]
+    fn deserialize_result() {
+        let result: AccessModeResult = serde_json::from_str(
+            r#"{"success": [{"public_id": "shoes/red", "status": "updated"}], "failed": []}"#,
+        )
+        .unwrap();
+        assert_eq!(result.success[0].public_id, "shoes/red");
+        assert!(result.failed.is_empty());
+    }
+}
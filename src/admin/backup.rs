@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, bail};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{Admin, Resource};
+use crate::upload::{DeliveryType, ResourceTypes, result::deserialize_from_str};
+
+/// A backed up version of an asset, available for assets uploaded with
+/// [`OptionalParameters::Backup`](crate::upload::OptionalParameters::Backup).
+#[derive(Clone, Deserialize, Debug)]
+pub struct AssetVersion {
+    /// Pass to [`Admin::restore`] to restore this particular version.
+    pub version_id: String,
+    pub version: usize,
+    pub format: String,
+    pub size: usize,
+    #[serde(deserialize_with = "deserialize_from_str")]
+    pub time: DateTime<Utc>,
+    pub restorable: bool,
+}
+
+#[derive(Deserialize)]
+struct VersionsResult {
+    #[serde(default)]
+    versions: Vec<AssetVersion>,
+}
+
+/// Outcome of restoring a single asset.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(untagged)]
+pub enum RestoreResult {
+    Restored(Box<Resource>),
+    Failed { error: String },
+}
+
+#[derive(Serialize)]
+struct Restore<'a> {
+    public_ids: &'a [&'a str],
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    versions: &'a [&'a str],
+}
+
+impl Admin {
+    /// Lists the backed up versions of an asset.
+    ///
+    /// ```rust
+    /// # async fn versions() {
+    /// use cloudinary::admin::Admin;
+    /// use cloudinary::upload::{DeliveryType, ResourceTypes};
+    ///
+    /// let admin = Admin::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string());
+    /// let versions = admin
+    ///     .asset_versions(ResourceTypes::Image, DeliveryType::Upload, "path/name")
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn asset_versions(
+        &self,
+        resource_type: ResourceTypes,
+        delivery_type: DeliveryType,
+        public_id: &str,
+    ) -> Result<Vec<AssetVersion>> {
+        let mut url = self.resource_endpoint(&resource_type, &delivery_type, public_id);
+        url.query_pairs_mut().append_pair("versions", "true");
+        let result: VersionsResult = self.get(url).await?;
+        Ok(result.versions)
+    }
+
+    /// Restores deleted assets from backup. Without `versions` the latest backed up version of every asset is
+    /// restored, otherwise `versions` holds a [`version_id`](AssetVersion::version_id) for each public id, in the same
+    /// order. Fails without sending a request when the lengths do not match.
+    ///
+    /// Returns the outcome keyed by public id.
+    pub async fn restore(
+        &self,
+        resource_type: ResourceTypes,
+        delivery_type: DeliveryType,
+        public_ids: &[&str],
+        versions: &[&str],
+    ) -> Result<HashMap<String, RestoreResult>> {
+        if !versions.is_empty() && versions.len() != public_ids.len() {
+            bail!(
+                "{} versions given for {} public ids",
+                versions.len(),
+                public_ids.len()
+            );
+        }
+        self.post(
+            self.endpoint(&[
+                "resources",
+                &resource_type.to_string(),
+                &delivery_type.to_string(),
+                "restore",
+            ]),
+            &Restore {
+                public_ids,
+                versions,
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn restore_body() {
+        assert_eq!(
+            serde_json::to_value(Restore {
+                public_ids: &["a", "b"],
+                versions: &[],
+            })
+            .unwrap(),
+            serde_json::json!({"public_ids": ["a", "b"]})
+        );
+    }
+
+    #[tokio::test]
+    async fn restore_with_mismatched_versions() {
+        let admin = Admin::new("api_key".into(), "cloud_name".into(), "api_secret".into());
+        let result = admin
+            .restore(
+                ResourceTypes::Image,
+                DeliveryType::Upload,
+                &["a", "b"],
+                &["4a25d7b64c5b3e5d9f1c4a2b3e5d7f6a"],
+            )
+            .await;
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "1 versions given for 2 public ids"
+        );
+    }
+
+    #[test]
+    fn deserialize_restore_result() {
+        let result: HashMap<String, RestoreResult> = serde_json::from_str(
+            r#"{
+                "image1": {
+                    "asset_id": "1a2b3c",
+                    "public_id": "image1",
+                    "format": "jpg",
+                    "version": 1719304891,
+                    "resource_type": "image",
+                    "type": "upload",
+                    "created_at": "2024-06-25T08:41:31Z",
+                    "bytes": 120253,
+                    "url": "http://res.cloudinary.com/demo/image/upload/v1719304891/image1.jpg",
+                    "secure_url": "https://res.cloudinary.com/demo/image/upload/v1719304891/image1.jpg"
+                },
+                "image2": {"error": "no_backup"}
+            }"#,
+        )
+        .unwrap();
+
+        assert!(matches!(&result["image1"], RestoreResult::Restored(r) if r.version == 1719304891));
+        assert!(
+            matches!(&result["image2"], RestoreResult::Failed { error } if error == "no_backup")
+        );
+    }
+
+    #[test]
+    fn deserialize_versions() {
+        let result: VersionsResult = serde_json::from_str(
+            r#"{
+                "versions": [{
+                    "version_id": "5552aa5ba4a71ba9bf0bfd7c5c2f4b42",
+                    "version": 1719304891,
+                    "format": "jpg",
+                    "size": 120253,
+                    "time": "2024-06-25T08:41:31+00:00",
+                    "restorable": true
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            result.versions[0].version_id,
+            "5552aa5ba4a71ba9bf0bfd7c5c2f4b42"
+        );
+        assert!(result.versions[0].restorable);
+    }
+}
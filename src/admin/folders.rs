@@ -0,0 +1,163 @@
+use anyhow::Result;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+
+use super::{Admin, Pagination};
+
+/// <https://cloudinary.com/documentation/admin_api#folders>
+#[derive(Clone, Deserialize, Debug, PartialEq, Eq)]
+pub struct Folder {
+    pub name: String,
+    pub path: String,
+    /// Only returned for accounts in [dynamic folder mode](https://cloudinary.com/documentation/folder_modes).
+    pub external_id: Option<String>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct FolderList {
+    pub folders: Vec<Folder>,
+    pub next_cursor: Option<String>,
+    pub total_count: usize,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct CreateFolderResult {
+    pub success: bool,
+    pub path: String,
+    pub name: String,
+    pub external_id: Option<String>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct RenameFolderResult {
+    pub from: Folder,
+    pub to: Folder,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct DeleteFolderResult {
+    /// Paths of the deleted folder and all its sub-folders.
+    pub deleted: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct RenameFolder<'a> {
+    to_folder: &'a str,
+}
+
+impl Admin {
+    /// Builds a `folders/...` endpoint keeping every folder of the path as a separate segment.
+    fn folder_endpoint(&self, path: &str) -> Url {
+        let mut segments = vec!["folders"];
+        segments.extend(path.split('/').filter(|s| !s.is_empty()));
+        self.endpoint(&segments)
+    }
+
+    /// Lists all root folders.
+    ///
+    /// ```rust
+    /// # async fn folders() {
+    /// use cloudinary::admin::{Admin, Pagination};
+    ///
+    /// let admin = Admin::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string());
+    /// let folders = admin.root_folders(&Pagination::default()).await;
+    /// # }
+    /// ```
+    pub async fn root_folders(&self, pagination: &Pagination) -> Result<FolderList> {
+        let mut url = self.endpoint(&["folders"]);
+        pagination.apply(&mut url);
+        self.get(url).await
+    }
+
+    /// Lists the sub-folders of the folder with the given full path, e.g. `products/shoes`.
+    pub async fn sub_folders(&self, path: &str, pagination: &Pagination) -> Result<FolderList> {
+        let mut url = self.folder_endpoint(path);
+        pagination.apply(&mut url);
+        self.get(url).await
+    }
+
+    /// Creates a folder, including any missing parent folders.
+    pub async fn create_folder(&self, path: &str) -> Result<CreateFolderResult> {
+        self.post(self.folder_endpoint(path), &serde_json::json!({}))
+            .await
+    }
+
+    /// Renames or moves a folder together with all its assets and sub-folders.
+    ///
+    /// Only supported by accounts in [dynamic folder mode](https://cloudinary.com/documentation/folder_modes).
+    pub async fn rename_folder(
+        &self,
+        from_path: &str,
+        to_path: &str,
+    ) -> Result<RenameFolderResult> {
+        self.put(
+            self.folder_endpoint(from_path),
+            &RenameFolder { to_folder: to_path },
+        )
+        .await
+    }
+
+    /// Deletes a folder together with its sub-folders.
+    ///
+    /// In fixed folder mode the folder must be empty. In dynamic folder mode assets that are still in the folder
+    /// are moved to the root folder, as folders are only a property of the asset there.
+    pub async fn delete_folder(&self, path: &str) -> Result<DeleteFolderResult> {
+        self.delete(self.folder_endpoint(path)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn folder_endpoint() {
+        let admin = Admin::new("key".into(), "cloud".into(), "secret".into());
+        assert_eq!(
+            admin.folder_endpoint("/products/summer shoes/").as_str(),
+            "https://api.cloudinary.com/v1_1/cloud/folders/products/summer%20shoes"
+        );
+    }
+
+    #[test]
+    fn deserialize_list() {
+        let list: FolderList = serde_json::from_str(
+            r#"{
+                "folders": [
+                    {"name": "cloud", "path": "cloud", "external_id": "c8a2ae2d1d0fe89b"},
+                    {"name": "shoes", "path": "products/shoes"}
+                ],
+                "next_cursor": null,
+                "total_count": 2
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(list.total_count, 2);
+        assert_eq!(list.next_cursor, None);
+        assert_eq!(
+            list.folders[1],
+            Folder {
+                name: "shoes".into(),
+                path: "products/shoes".into(),
+                external_id: None,
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_rename() {
+        let result: RenameFolderResult = serde_json::from_str(
+            r#"{
+                "from": {"name": "old", "path": "a/old"},
+                "to": {"name": "new", "path": "b/new"}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(result.from.path, "a/old");
+        assert_eq!(result.to.path, "b/new");
+    }
+}
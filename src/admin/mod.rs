@@ -0,0 +1,159 @@
+pub mod access_mode;
+pub mod backup;
+pub mod folders;
+pub mod moderation;
+pub mod related_assets;
+mod resource;
+pub mod search;
+pub mod streaming_profiles;
+pub mod transformations;
+pub mod triggers;
+pub mod upload_mappings;
+pub mod upload_presets;
+pub mod usage;
+
+use anyhow::{Context, Result, bail};
+use reqwest::{Client, Method, Url};
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::upload::result::Error;
+
+pub use self::resource::{Resource, ResourceList};
+
+/// Cursor based pagination shared by the Admin API listing methods.
+#[derive(Debug, Clone, Default)]
+pub struct Pagination {
+    /// Maximum number of results to return, up to 500. Default: 10.
+    pub max_results: Option<u32>,
+    /// The `next_cursor` value returned with the previous page.
+    pub next_cursor: Option<String>,
+}
+
+impl Pagination {
+    fn apply(&self, url: &mut Url) {
+        if let Some(max_results) = self.max_results {
+            url.query_pairs_mut()
+                .append_pair("max_results", &max_results.to_string());
+        }
+        if let Some(next_cursor) = &self.next_cursor {
+            url.query_pairs_mut()
+                .append_pair("next_cursor", next_cursor);
+        }
+    }
+}
+
+/// Client for the [Admin API](https://cloudinary.com/documentation/admin_api).
+///
+/// Unlike [`Upload`](crate::upload::Upload), requests are authenticated with HTTP basic authentication using the
+/// api_key and api_secret pair.
+pub struct Admin {
+    cloud_name: String,
+    api_key: String,
+    api_secret: String,
+}
+
+impl Admin {
+    pub fn new(api_key: String, cloud_name: String, api_secret: String) -> Self {
+        Admin {
+            api_key,
+            api_secret,
+            cloud_name,
+        }
+    }
+
+    /// Builds an Admin API endpoint. Every segment is percent-encoded separately, so folder paths and public ids
+    /// have to be split on `/` by the caller if they should stay separate segments.
+    fn endpoint(&self, segments: &[&str]) -> Url {
+        let mut url = Url::parse("https://api.cloudinary.com/v1_1").unwrap();
+        url.path_segments_mut()
+            .unwrap()
+            .push(&self.cloud_name)
+            .extend(segments);
+        url
+    }
+
+    async fn get<T: DeserializeOwned>(&self, url: Url) -> Result<T> {
+        self.call(Method::GET, url, None).await
+    }
+
+    async fn delete<T: DeserializeOwned>(&self, url: Url) -> Result<T> {
+        self.call(Method::DELETE, url, None).await
+    }
+
+    async fn post<T: DeserializeOwned, B: Serialize>(&self, url: Url, body: &B) -> Result<T> {
+        self.call(Method::POST, url, Some(serde_json::to_value(body)?))
+            .await
+    }
+
+    async fn put<T: DeserializeOwned, B: Serialize>(&self, url: Url, body: &B) -> Result<T> {
+        self.call(Method::PUT, url, Some(serde_json::to_value(body)?))
+            .await
+    }
+
+    async fn call<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        url: Url,
+        body: Option<serde_json::Value>,
+    ) -> Result<T> {
+        let client = Client::new();
+        let mut request = client
+            .request(method.clone(), url.clone())
+            .basic_auth(&self.api_key, Some(&self.api_secret));
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+        let response = request
+            .send()
+            .await
+            .context(format!("{} {}", method, url))?;
+        let status = response.status();
+        let text = response.text().await?;
+        if !status.is_success() {
+            let error: Error =
+                serde_json::from_str(&text).context(format!("failed to parse:\n\n {}", text))?;
+            bail!("{} {}: {}", method, url, error.error.message);
+        }
+        let json = serde_json::from_str(&text).context(format!("failed to parse:\n\n {}", text))?;
+        Ok(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Admin, Pagination};
+
+    #[test]
+    fn endpoint() {
+        let admin = Admin::new("key".into(), "cloud".into(), "secret".into());
+        assert_eq!(
+            admin.endpoint(&["resources", "search"]).as_str(),
+            "https://api.cloudinary.com/v1_1/cloud/resources/search"
+        );
+        assert_eq!(
+            admin.endpoint(&["folders", "with space", "a?b"]).as_str(),
+            "https://api.cloudinary.com/v1_1/cloud/folders/with%20space/a%3Fb"
+        );
+    }
+
+    #[test]
+    fn pagination() {
+        let admin = Admin::new("key".into(), "cloud".into(), "secret".into());
+        let mut url = admin.endpoint(&["folders"]);
+        Pagination::default().apply(&mut url);
+        assert_eq!(
+            url.as_str(),
+            "https://api.cloudinary.com/v1_1/cloud/folders"
+        );
+
+        Pagination {
+            max_results: Some(5),
+            next_cursor: Some("abc".into()),
+        }
+        .apply(&mut url);
+        assert_eq!(
+            url.as_str(),
+            "https://api.cloudinary.com/v1_1/cloud/folders?max_results=5&next_cursor=abc"
+        );
+    }
+}
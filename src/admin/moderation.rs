@@ -0,0 +1,148 @@
+use std::fmt::{Display, Formatter};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{Admin, Pagination, Resource, ResourceList};
+use crate::upload::{DeliveryType, Moderation, ResourceTypes};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModerationStatus {
+    Pending,
+    Approved,
+    Rejected,
+    /// Waiting for a previous moderation in the chain of moderations to complete.
+    Queued,
+    /// A previous moderation in the chain rejected the asset.
+    Aborted,
+}
+
+impl Display for ModerationStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModerationStatus::Pending => write!(f, "pending"),
+            ModerationStatus::Approved => write!(f, "approved"),
+            ModerationStatus::Rejected => write!(f, "rejected"),
+            ModerationStatus::Queued => write!(f, "queued"),
+            ModerationStatus::Aborted => write!(f, "aborted"),
+        }
+    }
+}
+
+/// The state of a single moderation of an asset.
+#[derive(Clone, Deserialize, Debug)]
+pub struct ModerationResult {
+    /// The moderation kind, e.g. `manual` or `aws_rek`.
+    pub kind: String,
+    pub status: ModerationStatus,
+    /// Add-on specific details of automatic moderations.
+    pub response: Option<Value>,
+    pub updated_at: Option<String>,
+}
+
+impl Admin {
+    /// Lists assets in the moderation queue of the given kind with the given status.
+    ///
+    /// ```rust
+    /// # async fn moderation() {
+    /// use cloudinary::admin::{Admin, Pagination, moderation::ModerationStatus};
+    /// use cloudinary::upload::{Moderation, ResourceTypes};
+    ///
+    /// let admin = Admin::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string());
+    /// let pending = admin
+    ///     .moderated_resources(
+    ///         ResourceTypes::Image,
+    ///         &Moderation::Manual,
+    ///         ModerationStatus::Pending,
+    ///         &Pagination::default(),
+    ///     )
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn moderated_resources(
+        &self,
+        resource_type: ResourceTypes,
+        kind: &Moderation,
+        status: ModerationStatus,
+        pagination: &Pagination,
+    ) -> Result<ResourceList> {
+        let mut url = self.endpoint(&[
+            "resources",
+            &resource_type.to_string(),
+            "moderations",
+            kind.kind(),
+            &status.to_string(),
+        ]);
+        pagination.apply(&mut url);
+        self.get(url).await
+    }
+
+    /// Approves or rejects the given assets of a manual moderation queue, one request per asset. Returns the outcome
+    /// for every public id in order, a failure does not stop the remaining assets from being updated. Updated assets
+    /// include their [`moderation`](Resource::moderation) results.
+    pub async fn update_moderation_status(
+        &self,
+        resource_type: ResourceTypes,
+        delivery_type: DeliveryType,
+        public_ids: &[&str],
+        status: ModerationStatus,
+    ) -> Vec<(String, Result<Resource>)> {
+        let mut results = Vec::with_capacity(public_ids.len());
+        for public_id in public_ids {
+            let result = self
+                .post(
+                    self.resource_endpoint(&resource_type, &delivery_type, public_id),
+                    &serde_json::json!({ "moderation_status": status }),
+                )
+                .await;
+            results.push((public_id.to_string(), result));
+        }
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_updated_resource() {
+        let resource: Resource = serde_json::from_str(
+            r#"{
+                "asset_id": "1a2b3c",
+                "public_id": "user_photo",
+                "format": "jpg",
+                "version": 1719304891,
+                "resource_type": "image",
+                "type": "upload",
+                "created_at": "2024-06-25T08:41:31Z",
+                "bytes": 120253,
+                "width": 864,
+                "height": 576,
+                "url": "http://res.cloudinary.com/demo/image/upload/v1719304891/user_photo.jpg",
+                "secure_url": "https://res.cloudinary.com/demo/image/upload/v1719304891/user_photo.jpg",
+                "moderation": [
+                    {"kind": "manual", "status": "approved", "updated_at": "2024-06-25T09:00:00Z"}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let moderation = resource.moderation.unwrap();
+        assert_eq!(moderation[0].kind, "manual");
+        assert_eq!(moderation[0].status, ModerationStatus::Approved);
+    }
+
+    #[test]
+    fn status_serialization() {
+        assert_eq!(
+            serde_json::to_value(ModerationStatus::Rejected).unwrap(),
+            "rejected"
+        );
+        assert_eq!(ModerationStatus::Pending.to_string(), "pending");
+    }
+}
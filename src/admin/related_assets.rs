@@ -0,0 +1,176 @@
+use anyhow::Result;
+use reqwest::{Method, Url};
+use serde::Deserialize;
+
+use super::Admin;
+use crate::upload::{DeliveryType, ResourceTypes};
+
+/// An asset related to another one, as returned in [`Resource::related_assets`](super::Resource::related_assets).
+#[derive(Clone, Deserialize, Debug)]
+pub struct RelatedAsset {
+    pub asset_id: String,
+    pub public_id: String,
+    pub resource_type: String,
+    pub r#type: String,
+    pub format: Option<String>,
+    pub secure_url: Option<String>,
+}
+
+/// The outcome of adding or removing a single relation.
+#[derive(Clone, Deserialize, Debug)]
+pub struct Relation {
+    pub message: String,
+    pub code: String,
+    /// The related asset as it was passed to the request.
+    pub asset: String,
+    pub status: u16,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct RelationsResult {
+    #[serde(default)]
+    pub success: Vec<Relation>,
+    #[serde(default)]
+    pub failed: Vec<Relation>,
+}
+
+impl Admin {
+    fn related_assets_endpoint(
+        &self,
+        resource_type: &ResourceTypes,
+        delivery_type: &DeliveryType,
+        public_id: &str,
+    ) -> Url {
+        let resource_type = resource_type.to_string();
+        let delivery_type = delivery_type.to_string();
+        let mut segments = vec![
+            "resources",
+            "related_assets",
+            &resource_type,
+            &delivery_type,
+        ];
+        segments.extend(public_id.split('/'));
+        self.endpoint(&segments)
+    }
+
+    /// Relates assets to the given asset. Related assets are referenced as `<resource_type>/<type>/<public_id>`, e.g.
+    /// `image/upload/articles/hero_square`. Relations are bidirectional.
+    ///
+    /// ```rust
+    /// # async fn relate() {
+    /// use cloudinary::admin::Admin;
+    /// use cloudinary::upload::{DeliveryType, ResourceTypes};
+    ///
+    /// let admin = Admin::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string());
+    /// let result = admin
+    ///     .add_related_assets(
+    ///         ResourceTypes::Image,
+    ///         DeliveryType::Upload,
+    ///         "articles/hero",
+    ///         &["image/upload/articles/hero_square", "video/upload/articles/hero_loop"],
+    ///     )
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn add_related_assets(
+        &self,
+        resource_type: ResourceTypes,
+        delivery_type: DeliveryType,
+        public_id: &str,
+        assets: &[&str],
+    ) -> Result<RelationsResult> {
+        self.post(
+            self.related_assets_endpoint(&resource_type, &delivery_type, public_id),
+            &serde_json::json!({ "assets_to_relate": assets }),
+        )
+        .await
+    }
+
+    /// Removes relations added with [`Admin::add_related_assets`].
+    pub async fn remove_related_assets(
+        &self,
+        resource_type: ResourceTypes,
+        delivery_type: DeliveryType,
+        public_id: &str,
+        assets: &[&str],
+    ) -> Result<RelationsResult> {
+        self.call(
+            Method::DELETE,
+            self.related_assets_endpoint(&resource_type, &delivery_type, public_id),
+            Some(serde_json::json!({ "assets_to_unrelate": assets })),
+        )
+        .await
+    }
+
+    /// Relates the assets with the given asset ids to the asset with `asset_id`.
+    pub async fn add_related_assets_by_asset_id(
+        &self,
+        asset_id: &str,
+        asset_ids: &[&str],
+    ) -> Result<RelationsResult> {
+        self.post(
+            self.endpoint(&["resources", "related_assets", asset_id]),
+            &serde_json::json!({ "assets_to_relate": asset_ids }),
+        )
+        .await
+    }
+
+    pub async fn remove_related_assets_by_asset_id(
+        &self,
+        asset_id: &str,
+        asset_ids: &[&str],
+    ) -> Result<RelationsResult> {
+        self.call(
+            Method::DELETE,
+            self.endpoint(&["resources", "related_assets", asset_id]),
+            Some(serde_json::json!({ "assets_to_unrelate": asset_ids })),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn related_assets_endpoint() {
+        let admin = Admin::new("key".into(), "cloud".into(), "secret".into());
+        assert_eq!(
+            admin
+                .related_assets_endpoint(
+                    &ResourceTypes::Image,
+                    &DeliveryType::Upload,
+                    "articles/hero"
+                )
+                .as_str(),
+            "https://api.cloudinary.com/v1_1/cloud/resources/related_assets/image/upload/articles/hero"
+        );
+    }
+
+    #[test]
+    fn deserialize_result() {
+        let result: RelationsResult = serde_json::from_str(
+            r#"{
+                "failed": [{
+                    "message": "resource does not exist",
+                    "code": "invalid_resource",
+                    "asset": "image/upload/missing",
+                    "status": 404
+                }],
+                "success": [{
+                    "message": "success",
+                    "code": "success_ids",
+                    "asset": "image/upload/articles/hero_square",
+                    "status": 200
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(result.success[0].asset, "image/upload/articles/hero_square");
+        assert_eq!(result.failed[0].status, 404);
+    }
+}
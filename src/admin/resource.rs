@@ -0,0 +1,150 @@
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+use serde::Deserialize;
+
+use anyhow::Result;
+
+use super::{Admin, moderation::ModerationResult, related_assets::RelatedAsset};
+use crate::upload::{AccessControl, DeliveryType, ResourceTypes, result::deserialize_from_str};
+
+/// An asset as returned by the Admin API listing and search methods.
+///
+/// Which of the optional fields are present depends on the resource type and on the fields requested
+/// (e.g. via [`Search::with_field`](super::search::Search::with_field)).
+///
+/// <https://cloudinary.com/documentation/admin_api#get_resources>
+#[derive(Clone, Deserialize, Debug)]
+pub struct Resource {
+    pub asset_id: String,
+    pub public_id: String,
+    pub folder: Option<String>,
+    pub asset_folder: Option<String>,
+    pub filename: Option<String>,
+    pub display_name: Option<String>,
+    /// Not present for raw assets.
+    pub format: Option<String>,
+    pub version: usize,
+    pub resource_type: String,
+    pub r#type: String,
+    #[serde(deserialize_with = "deserialize_from_str")]
+    pub created_at: DateTime<Utc>,
+    pub bytes: usize,
+    pub width: Option<usize>,
+    pub height: Option<usize>,
+    pub aspect_ratio: Option<f64>,
+    pub pixels: Option<usize>,
+    pub duration: Option<f64>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub context: Option<serde_json::Value>,
+    pub metadata: Option<serde_json::Value>,
+    pub url: String,
+    pub secure_url: String,
+    pub status: Option<String>,
+    pub access_mode: Option<String>,
+    pub etag: Option<String>,
+    pub backup: Option<bool>,
+    /// Returned by resource details and updates of moderated assets.
+    pub moderation: Option<Vec<ModerationResult>>,
+    /// Only returned by [`Admin::resource`].
+    pub related_assets: Option<Vec<RelatedAsset>>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct ResourceList {
+    pub resources: Vec<Resource>,
+    pub next_cursor: Option<String>,
+}
+
+impl Admin {
+    /// Builds a `resources/<resource_type>/<type>/<public_id>` endpoint keeping the folders of the public id as
+    /// separate segments.
+    pub(super) fn resource_endpoint(
+        &self,
+        resource_type: &ResourceTypes,
+        delivery_type: &DeliveryType,
+        public_id: &str,
+    ) -> Url {
+        let resource_type = resource_type.to_string();
+        let delivery_type = delivery_type.to_string();
+        let mut segments = vec!["resources", &resource_type, &delivery_type];
+        segments.extend(public_id.split('/'));
+        self.endpoint(&segments)
+    }
+
+    /// Loads the details of a single asset, including the assets related to it.
+    ///
+    /// ```rust
+    /// # async fn resource() {
+    /// use cloudinary::admin::Admin;
+    /// use cloudinary::upload::{DeliveryType, ResourceTypes};
+    ///
+    /// let admin = Admin::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string());
+    /// let hero = admin
+    ///     .resource(ResourceTypes::Image, DeliveryType::Upload, "articles/hero")
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn resource(
+        &self,
+        resource_type: ResourceTypes,
+        delivery_type: DeliveryType,
+        public_id: &str,
+    ) -> Result<Resource> {
+        let mut url = self.resource_endpoint(&resource_type, &delivery_type, public_id);
+        url.query_pairs_mut().append_pair("related", "true");
+        self.get(url).await
+    }
+
+    /// Replaces the access control of an existing asset. An empty list removes the restriction.
+    ///
+    /// ```rust
+    /// # async fn access_control() {
+    /// use cloudinary::admin::Admin;
+    /// use cloudinary::upload::{AccessControl, DeliveryType, ResourceTypes};
+    ///
+    /// let admin = Admin::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string());
+    /// let resource = admin
+    ///     .update_access_control(
+    ///         ResourceTypes::Image,
+    ///         DeliveryType::Upload,
+    ///         "launch/teaser",
+    ///         &[AccessControl::Token],
+    ///     )
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn update_access_control(
+        &self,
+        resource_type: ResourceTypes,
+        delivery_type: DeliveryType,
+        public_id: &str,
+        access_control: &[AccessControl],
+    ) -> Result<Resource> {
+        self.post(
+            self.resource_endpoint(&resource_type, &delivery_type, public_id),
+            &serde_json::json!({ "access_control": access_control }),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resource_endpoint() {
+        let admin = Admin::new("key".into(), "cloud".into(), "secret".into());
+        assert_eq!(
+            admin
+                .resource_endpoint(
+                    &ResourceTypes::Video,
+                    &DeliveryType::Private,
+                    "path/my video"
+                )
+                .as_str(),
+            "https://api.cloudinary.com/v1_1/cloud/resources/video/private/path/my%20video"
+        );
+    }
+}
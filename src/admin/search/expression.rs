@@ -0,0 +1,255 @@
+use std::fmt::{Display, Formatter};
+use std::ops::Not;
+
+use chrono::NaiveDate;
+use serde::{Serialize, Serializer};
+
+use crate::upload::ResourceTypes;
+
+/// Characters that have a special meaning in the search expression language. Values containing any of them (or
+/// whitespace) are wrapped in double quotes.
+const RESERVED: &[char] = &[
+    ':', '=', '<', '>', '(', ')', '[', ']', '{', '}', '!', '&', '|', '"', '\\', '^', '~', '+', '-',
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operator {
+    /// `:` - tokenized match, e.g. `tags:shoe` also matches the `red shoe` tag.
+    Match,
+    /// `=` - exact match.
+    Equal,
+    Less,
+    Greater,
+    LessOrEqual,
+    GreaterOrEqual,
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operator::Match => write!(f, ":"),
+            Operator::Equal => write!(f, "="),
+            Operator::Less => write!(f, "<"),
+            Operator::Greater => write!(f, ">"),
+            Operator::LessOrEqual => write!(f, "<="),
+            Operator::GreaterOrEqual => write!(f, ">="),
+        }
+    }
+}
+
+/// A point in time for date fields such as `created_at` or `uploaded_at`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Time {
+    Date(NaiveDate),
+    /// Relative to now, e.g. `3d`.
+    DaysAgo(u32),
+    /// Relative to now, e.g. `4w`.
+    WeeksAgo(u32),
+}
+
+impl Display for Time {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Time::Date(date) => write!(f, "{}", date.format("%Y-%m-%d")),
+            Time::DaysAgo(days) => write!(f, "{}d", days),
+            Time::WeeksAgo(weeks) => write!(f, "{}w", weeks),
+        }
+    }
+}
+
+/// A [search expression](https://cloudinary.com/documentation/search_expressions).
+///
+/// ```rust
+/// use cloudinary::admin::search::{Expression, Operator};
+/// use cloudinary::upload::ResourceTypes;
+///
+/// let expression = Expression::ResourceType(ResourceTypes::Image)
+///     .and(Expression::Tag("spring sale".into()).or(Expression::Folder("shoes".into())))
+///     .and(!Expression::Bytes(Operator::Greater, 1_000_000));
+/// assert_eq!(
+///     expression.to_string(),
+///     r#"resource_type:image AND (tags="spring sale" OR folder=shoes) AND NOT bytes>1000000"#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    /// `tags=<tag>` - assets with exactly this tag.
+    Tag(String),
+    /// `folder=<path>` - assets directly in this folder.
+    Folder(String),
+    /// `resource_type:<type>`
+    ResourceType(ResourceTypes),
+    /// `created_at<operator><time>`
+    CreatedAt(Operator, Time),
+    /// `bytes<operator><size>`
+    Bytes(Operator, u64),
+    /// Any other field, e.g. `Field("context.alt".into(), Operator::Match, "dog".into())`.
+    Field(String, Operator, String),
+    /// A free text term searched across all string fields.
+    Term(String),
+    And(Vec<Expression>),
+    Or(Vec<Expression>),
+    Not(Box<Expression>),
+}
+
+impl Expression {
+    /// Combines two expressions with `AND`, flattening nested `AND`s.
+    pub fn and(self, other: Expression) -> Self {
+        match self {
+            Expression::And(mut expressions) => {
+                expressions.push(other);
+                Expression::And(expressions)
+            }
+            expression => Expression::And(vec![expression, other]),
+        }
+    }
+
+    /// Combines two expressions with `OR`, flattening nested `OR`s.
+    pub fn or(self, other: Expression) -> Self {
+        match self {
+            Expression::Or(mut expressions) => {
+                expressions.push(other);
+                Expression::Or(expressions)
+            }
+            expression => Expression::Or(vec![expression, other]),
+        }
+    }
+
+    fn is_compound(&self) -> bool {
+        matches!(self, Expression::And(_) | Expression::Or(_))
+    }
+
+    fn fmt_operand(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_compound() {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+
+    fn fmt_joined(
+        f: &mut Formatter<'_>,
+        expressions: &[Expression],
+        separator: &str,
+    ) -> std::fmt::Result {
+        for (i, expression) in expressions.iter().enumerate() {
+            if i > 0 {
+                f.write_str(separator)?;
+            }
+            expression.fmt_operand(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Not for Expression {
+    type Output = Expression;
+
+    fn not(self) -> Self::Output {
+        Expression::Not(Box::new(self))
+    }
+}
+
+/// Wraps the value in double quotes if it contains whitespace or reserved characters.
+fn quote(value: &str) -> String {
+    if value.is_empty() || value.contains(|c: char| c.is_whitespace() || RESERVED.contains(&c)) {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        value.to_string()
+    }
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expression::Tag(tag) => write!(f, "tags={}", quote(tag)),
+            Expression::Folder(folder) => write!(f, "folder={}", quote(folder)),
+            Expression::ResourceType(resource_type) => write!(f, "resource_type:{}", resource_type),
+            Expression::CreatedAt(operator, time) => write!(f, "created_at{}{}", operator, time),
+            Expression::Bytes(operator, bytes) => write!(f, "bytes{}{}", operator, bytes),
+            Expression::Field(name, operator, value) => {
+                write!(f, "{}{}{}", name, operator, quote(value))
+            }
+            Expression::Term(term) => write!(f, "{}", quote(term)),
+            Expression::And(expressions) => Expression::fmt_joined(f, expressions, " AND "),
+            Expression::Or(expressions) => Expression::fmt_joined(f, expressions, " OR "),
+            Expression::Not(expression) => {
+                f.write_str("NOT ")?;
+                expression.fmt_operand(f)
+            }
+        }
+    }
+}
+
+impl Serialize for Expression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn quoting() {
+        assert_eq!(Expression::Tag("shoe".into()).to_string(), "tags=shoe");
+        assert_eq!(
+            Expression::Tag("red shoe".into()).to_string(),
+            "tags=\"red shoe\""
+        );
+        assert_eq!(
+            Expression::Field("context.alt".into(), Operator::Match, "say \"hi\"".into())
+                .to_string(),
+            r#"context.alt:"say \"hi\"""#
+        );
+        assert_eq!(Expression::Folder("a/b".into()).to_string(), "folder=a/b");
+        assert_eq!(Expression::Term("".into()).to_string(), "\"\"");
+    }
+
+    #[test]
+    fn comparisons() {
+        assert_eq!(
+            Expression::CreatedAt(
+                Operator::Greater,
+                Time::Date(NaiveDate::from_ymd_opt(2024, 1, 2).unwrap())
+            )
+            .to_string(),
+            "created_at>2024-01-02"
+        );
+        assert_eq!(
+            Expression::CreatedAt(Operator::LessOrEqual, Time::WeeksAgo(4)).to_string(),
+            "created_at<=4w"
+        );
+        assert_eq!(
+            Expression::Bytes(Operator::GreaterOrEqual, 10).to_string(),
+            "bytes>=10"
+        );
+        assert_eq!(
+            Expression::ResourceType(ResourceTypes::Video).to_string(),
+            "resource_type:video"
+        );
+    }
+
+    #[test]
+    fn boolean() {
+        let expression = Expression::Tag("a".into())
+            .and(Expression::Tag("b".into()))
+            .and(Expression::Tag("c".into()));
+        assert_eq!(expression.to_string(), "tags=a AND tags=b AND tags=c");
+
+        let expression = Expression::Tag("a".into())
+            .or(Expression::Tag("b".into()))
+            .and(Expression::Tag("c".into()).or(Expression::Tag("d".into())));
+        assert_eq!(
+            expression.to_string(),
+            "(tags=a OR tags=b) AND (tags=c OR tags=d)"
+        );
+
+        let expression = !(Expression::Tag("a".into()).and(Expression::Tag("b".into())));
+        assert_eq!(expression.to_string(), "NOT (tags=a AND tags=b)");
+    }
+}
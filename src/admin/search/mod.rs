@@ -0,0 +1,175 @@
+mod expression;
+mod result;
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+use futures_util::{Stream, TryStreamExt, stream};
+use serde::Serialize;
+
+use super::{Admin, Resource};
+
+pub use self::{
+    expression::{Expression, Operator, Time},
+    result::SearchResult,
+};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+/// A query for the [Search API](https://cloudinary.com/documentation/search_method).
+///
+/// ```rust
+/// use cloudinary::admin::search::{Expression, Search, SortDirection};
+///
+/// let search = Search::new()
+///     .expression(Expression::Tag("shoes".into()))
+///     .sort_by("created_at", SortDirection::Desc)
+///     .aggregate("format")
+///     .with_field("tags")
+///     .max_results(100);
+/// ```
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Search {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expression: Option<Expression>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    sort_by: Vec<HashMap<String, SortDirection>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    aggregate: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    with_field: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_results: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_cursor: Option<String>,
+}
+
+impl Search {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Without an expression all assets are matched.
+    pub fn expression(mut self, expression: Expression) -> Self {
+        self.expression = Some(expression);
+        self
+    }
+
+    /// Can be called multiple times, earlier fields take precedence. Default: `created_at` descending.
+    pub fn sort_by<S: Into<String>>(mut self, field: S, direction: SortDirection) -> Self {
+        self.sort_by
+            .push(HashMap::from([(field.into(), direction)]));
+        self
+    }
+
+    /// Adds counts of the matched assets grouped by the field, e.g. `format` or `resource_type`.
+    pub fn aggregate<S: Into<String>>(mut self, field: S) -> Self {
+        self.aggregate.push(field.into());
+        self
+    }
+
+    /// Includes an additional field in the response, e.g. `tags`, `context` or `metadata`.
+    pub fn with_field<S: Into<String>>(mut self, field: S) -> Self {
+        self.with_field.push(field.into());
+        self
+    }
+
+    /// Up to 500. Default: 50.
+    pub fn max_results(mut self, max_results: u32) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// The `next_cursor` of the previous page.
+    pub fn next_cursor<S: Into<String>>(mut self, next_cursor: S) -> Self {
+        self.next_cursor = Some(next_cursor.into());
+        self
+    }
+}
+
+impl Admin {
+    /// Loads a single page of the search results.
+    ///
+    /// ```rust
+    /// # async fn search() {
+    /// use cloudinary::admin::{Admin, search::{Expression, Search}};
+    ///
+    /// let admin = Admin::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string());
+    /// let result = admin.search(&Search::new().expression(Expression::Tag("shoes".into()))).await;
+    /// # }
+    /// ```
+    pub async fn search(&self, search: &Search) -> Result<SearchResult> {
+        self.post(self.endpoint(&["resources", "search"]), search)
+            .await
+    }
+
+    /// Streams all resources matching the search, following `next_cursor` until the last page.
+    ///
+    /// ```rust
+    /// # async fn search() {
+    /// use futures_util::TryStreamExt;
+    /// use cloudinary::admin::{Admin, search::{Expression, Search}};
+    ///
+    /// let admin = Admin::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string());
+    /// let resources: Vec<_> = admin
+    ///     .search_stream(Search::new().expression(Expression::Tag("shoes".into())))
+    ///     .try_collect()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn search_stream(&self, search: Search) -> impl Stream<Item = Result<Resource>> + '_ {
+        stream::try_unfold(Some(search), move |search| async move {
+            let Some(search) = search else {
+                return anyhow::Ok(None);
+            };
+            let page = self.search(&search).await?;
+            let next = page.next_cursor.map(|cursor| search.next_cursor(cursor));
+            Ok(Some((
+                stream::iter(page.resources.into_iter().map(Ok)),
+                next,
+            )))
+        })
+        .try_flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn serialize_empty() {
+        assert_eq!(serde_json::to_string(&Search::new()).unwrap(), "{}");
+    }
+
+    #[test]
+    fn serialize() {
+        let search = Search::new()
+            .expression(Expression::Tag("a b".into()).and(Expression::Folder("c".into())))
+            .sort_by("public_id", SortDirection::Asc)
+            .aggregate("format")
+            .with_field("tags")
+            .with_field("context")
+            .max_results(10)
+            .next_cursor("abc");
+        assert_eq!(
+            serde_json::to_value(&search).unwrap(),
+            serde_json::json!({
+                "expression": "tags=\"a b\" AND folder=c",
+                "sort_by": [{"public_id": "asc"}],
+                "aggregate": ["format"],
+                "with_field": ["tags", "context"],
+                "max_results": 10,
+                "next_cursor": "abc"
+            })
+        );
+    }
+}
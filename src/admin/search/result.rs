@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::admin::Resource;
+
+/// <https://cloudinary.com/documentation/search_method#sample_response>
+#[derive(Clone, Deserialize, Debug)]
+pub struct SearchResult {
+    pub total_count: usize,
+    /// Time the search took in milliseconds.
+    pub time: u64,
+    pub resources: Vec<Resource>,
+    /// Present when there are more results than `max_results`.
+    pub next_cursor: Option<String>,
+    /// Counts per value of every aggregated field, e.g. `{"format": {"png": 2, "jpg": 1}}`.
+    #[serde(default)]
+    pub aggregations: HashMap<String, HashMap<String, u64>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SearchResult;
+
+    #[test]
+    fn deserialize() {
+        let result: SearchResult = serde_json::from_str(
+            r#"{
+                "total_count": 1,
+                "time": 12,
+                "aggregations": {"format": {"png": 1}},
+                "next_cursor": "b16b8bd80426df43a107f26b0348",
+                "resources": [{
+                    "asset_id": "f9f8d8c7",
+                    "public_id": "shoes/red",
+                    "folder": "shoes",
+                    "filename": "red",
+                    "format": "png",
+                    "version": 1719304891,
+                    "resource_type": "image",
+                    "type": "upload",
+                    "created_at": "2024-06-25T08:41:31+00:00",
+                    "uploaded_at": "2024-06-25T08:41:31+00:00",
+                    "bytes": 95,
+                    "width": 1,
+                    "height": 1,
+                    "aspect_ratio": 1.0,
+                    "pixels": 1,
+                    "tags": ["shoes"],
+                    "url": "http://res.cloudinary.com/demo/image/upload/v1719304891/shoes/red.png",
+                    "secure_url": "https://res.cloudinary.com/demo/image/upload/v1719304891/shoes/red.png",
+                    "status": "active",
+                    "access_mode": "public",
+                    "access_control": null,
+                    "etag": "d5a0ad5ba8a7de9ef2f4e0ad3c4a8e70"
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(result.total_count, 1);
+        assert_eq!(result.aggregations["format"]["png"], 1);
+        assert_eq!(result.resources[0].public_id, "shoes/red");
+        assert_eq!(result.resources[0].tags, vec!["shoes".to_string()]);
+        assert_eq!(
+            result.next_cursor.as_deref(),
+            Some("b16b8bd80426df43a107f26b0348")
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::Value;
+
+use super::Admin;
+use crate::transformation::Transformations;
+
+/// A single rendition of an adaptive bitrate streaming profile.
+#[derive(Debug, Clone)]
+pub struct Representation {
+    pub transformations: Vec<Transformations>,
+}
+
+impl Serialize for Representation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_json::json!({ "transformation": self.transformations.iter().join("/") })
+            .serialize(serializer)
+    }
+}
+
+/// A [streaming profile](https://cloudinary.com/documentation/admin_api#adaptive_streaming_profiles) declared in
+/// code.
+///
+/// ```rust
+/// use cloudinary::admin::streaming_profiles::{Representation, StreamingProfile};
+/// use cloudinary::transformation::{ResizeMode, Transformations};
+///
+/// let profile = StreamingProfile {
+///     name: "custom_square".into(),
+///     display_name: Some("Custom square resolution".into()),
+///     representations: [1200, 900, 600]
+///         .into_iter()
+///         .map(|side| Representation {
+///             transformations: vec![Transformations::Resize(ResizeMode::Limit {
+///                 width: side,
+///                 height: side,
+///             })],
+///         })
+///         .collect(),
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct StreamingProfile {
+    pub name: String,
+    pub display_name: Option<String>,
+    /// Ordered from the highest to the lowest quality.
+    pub representations: Vec<Representation>,
+}
+
+impl StreamingProfile {
+    fn body(&self, with_name: bool) -> Result<HashMap<&'static str, String>> {
+        let mut body = HashMap::from([(
+            "representations",
+            serde_json::to_string(&self.representations)?,
+        )]);
+        if with_name {
+            body.insert("name", self.name.clone());
+        }
+        if let Some(display_name) = &self.display_name {
+            body.insert("display_name", display_name.clone());
+        }
+        Ok(body)
+    }
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct RepresentationDetails {
+    /// The parameters of every transformation in the chain, e.g. `{"crop": "limit", "width": 1200}`.
+    pub transformation: Vec<HashMap<String, Value>>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct StreamingProfileInfo {
+    pub name: String,
+    pub display_name: Option<String>,
+    /// Predefined profiles can be updated, deleting them reverts them to the default definition.
+    pub predefined: bool,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct StreamingProfileDetails {
+    pub name: String,
+    pub display_name: Option<String>,
+    pub predefined: bool,
+    pub representations: Vec<RepresentationDetails>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct StreamingProfileList {
+    pub data: Vec<StreamingProfileInfo>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct StreamingProfileResult {
+    pub data: StreamingProfileDetails,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct StreamingProfileMessage {
+    /// `created`, `updated` or `deleted`.
+    pub message: String,
+    /// Not returned on deletion.
+    pub data: Option<StreamingProfileDetails>,
+}
+
+impl Admin {
+    /// Lists predefined and custom streaming profiles.
+    ///
+    /// ```rust
+    /// # async fn profiles() {
+    /// use cloudinary::admin::Admin;
+    ///
+    /// let admin = Admin::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string());
+    /// let profiles = admin.streaming_profiles().await;
+    /// # }
+    /// ```
+    pub async fn streaming_profiles(&self) -> Result<StreamingProfileList> {
+        self.get(self.endpoint(&["streaming_profiles"])).await
+    }
+
+    pub async fn streaming_profile(&self, name: &str) -> Result<StreamingProfileResult> {
+        self.get(self.endpoint(&["streaming_profiles", name])).await
+    }
+
+    pub async fn create_streaming_profile(
+        &self,
+        profile: &StreamingProfile,
+    ) -> Result<StreamingProfileMessage> {
+        self.post(self.endpoint(&["streaming_profiles"]), &profile.body(true)?)
+            .await
+    }
+
+    /// Replaces the representations of the profile with the given name.
+    pub async fn update_streaming_profile(
+        &self,
+        profile: &StreamingProfile,
+    ) -> Result<StreamingProfileMessage> {
+        self.put(
+            self.endpoint(&["streaming_profiles", &profile.name]),
+            &profile.body(false)?,
+        )
+        .await
+    }
+
+    /// Deletes a custom profile or reverts a predefined one to its original definition.
+    pub async fn delete_streaming_profile(&self, name: &str) -> Result<StreamingProfileMessage> {
+        self.delete(self.endpoint(&["streaming_profiles", name]))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::transformation::ResizeMode;
+
+    #[test]
+    fn body() {
+        let profile = StreamingProfile {
+            name: "square".into(),
+            display_name: None,
+            representations: vec![
+                Representation {
+                    transformations: vec![Transformations::Resize(ResizeMode::Limit {
+                        width: 1200,
+                        height: 1200,
+                    })],
+                },
+                Representation {
+                    transformations: vec![Transformations::Named("sd".into())],
+                },
+            ],
+        };
+
+        assert_eq!(
+            profile.body(true).unwrap(),
+            HashMap::from([
+                ("name", "square".to_string()),
+                (
+                    "representations",
+                    r#"[{"transformation":"c_limit,w_1200,h_1200"},{"transformation":"t_sd"}]"#
+                        .to_string()
+                ),
+            ])
+        );
+        assert!(!profile.body(false).unwrap().contains_key("name"));
+    }
+
+    #[test]
+    fn deserialize_details() {
+        let result: StreamingProfileResult = serde_json::from_str(
+            r#"{
+                "data": {
+                    "name": "custom_square",
+                    "display_name": "Custom square resolution",
+                    "predefined": false,
+                    "representations": [
+                        {"transformation": [{"width": 1200, "height": 1200, "crop": "limit"}]},
+                        {"transformation": [{"width": 900, "height": 900, "crop": "limit"}]}
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(result.data.representations.len(), 2);
+        assert_eq!(
+            result.data.representations[1].transformation[0]["width"],
+            900
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use itertools::Itertools;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{Admin, Pagination};
+use crate::transformation::Transformations;
+
+/// A reusable chain of transformations stored on the account under a name.
+///
+/// ```rust
+/// use cloudinary::admin::transformations::NamedTransformation;
+/// use cloudinary::transformation::{Image, Transformations, CropMode};
+///
+/// let thumbnail_card = NamedTransformation::new(
+///     "thumbnail_card",
+///     vec![Transformations::Crop(CropMode::Fill { width: 300, height: 200, gravity: None })],
+/// );
+/// let image = Image::new("test".into(), "path/name.png".into())
+///     .add_transformation(thumbnail_card.transformation());
+/// assert_eq!(
+///     image.to_string(),
+///     "https://res.cloudinary.com/test/image/upload/t_thumbnail_card/path/name.png"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct NamedTransformation {
+    /// Name without the `t_` prefix.
+    pub name: String,
+    pub transformations: Vec<Transformations>,
+}
+
+impl NamedTransformation {
+    pub fn new<S: Into<String>>(name: S, transformations: Vec<Transformations>) -> Self {
+        Self {
+            name: name.into(),
+            transformations,
+        }
+    }
+
+    /// A [`Transformations::Named`] referencing this transformation in delivery URLs.
+    pub fn transformation(&self) -> Transformations {
+        Transformations::Named(self.name.clone())
+    }
+}
+
+/// Parameters of [`Admin::update_transformation`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TransformationUpdate {
+    /// Whether the transformation can be used when
+    /// [strict transformations](https://cloudinary.com/documentation/control_access_to_media#strict_transformations)
+    /// are enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_for_strict: Option<bool>,
+    /// Replaces the definition of a named transformation, even if it is already used by derived assets.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_chain"
+    )]
+    pub unsafe_update: Option<Vec<Transformations>>,
+}
+
+fn serialize_chain<S: serde::Serializer>(
+    chain: &Option<Vec<Transformations>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match chain {
+        Some(chain) => serializer.collect_str(&chain.iter().join("/")),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct TransformationInfo {
+    /// The transformation string, or `t_<name>` for named transformations.
+    pub name: String,
+    pub allowed_for_strict: bool,
+    pub used: bool,
+    pub named: bool,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct TransformationList {
+    pub transformations: Vec<TransformationInfo>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct DerivedAsset {
+    pub public_id: String,
+    pub resource_type: String,
+    pub r#type: String,
+    pub format: String,
+    pub url: String,
+    pub secure_url: String,
+    pub bytes: usize,
+    pub id: String,
+}
+
+/// <https://cloudinary.com/documentation/admin_api#get_transformation_details>
+#[derive(Clone, Deserialize, Debug)]
+pub struct TransformationDetails {
+    pub name: String,
+    pub allowed_for_strict: bool,
+    pub used: bool,
+    pub named: bool,
+    /// The parameters of every transformation in the chain, e.g. `{"crop": "fill", "width": 300}`.
+    #[serde(default)]
+    pub info: Vec<HashMap<String, Value>>,
+    #[serde(default)]
+    pub derived: Vec<DerivedAsset>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct TransformationResult {
+    /// `created`, `updated` or `deleted`.
+    pub message: String,
+}
+
+impl Admin {
+    fn transformation_endpoint(&self, transformation: &str) -> Url {
+        let mut url = self.endpoint(&["transformations"]);
+        url.query_pairs_mut()
+            .append_pair("transformation", transformation);
+        url
+    }
+
+    /// Lists the transformations of the account. When `named` is set, only named (or only unnamed) transformations
+    /// are returned.
+    ///
+    /// ```rust
+    /// # async fn transformations() {
+    /// use cloudinary::admin::{Admin, Pagination};
+    ///
+    /// let admin = Admin::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string());
+    /// let named = admin.transformations(Some(true), &Pagination::default()).await;
+    /// # }
+    /// ```
+    pub async fn transformations(
+        &self,
+        named: Option<bool>,
+        pagination: &Pagination,
+    ) -> Result<TransformationList> {
+        let mut url = self.endpoint(&["transformations"]);
+        if let Some(named) = named {
+            url.query_pairs_mut()
+                .append_pair("named", &named.to_string());
+        }
+        pagination.apply(&mut url);
+        self.get(url).await
+    }
+
+    /// Loads a transformation by the name of a named transformation or by a transformation string.
+    pub async fn transformation(&self, transformation: &str) -> Result<TransformationDetails> {
+        self.get(self.transformation_endpoint(transformation)).await
+    }
+
+    pub async fn create_transformation(
+        &self,
+        named: &NamedTransformation,
+    ) -> Result<TransformationResult> {
+        self.post(
+            self.endpoint(&["transformations"]),
+            &serde_json::json!({
+                "name": named.name,
+                "transformation": named.transformations.iter().join("/"),
+            }),
+        )
+        .await
+    }
+
+    /// Updates a transformation by the name of a named transformation or by a transformation string.
+    pub async fn update_transformation(
+        &self,
+        transformation: &str,
+        update: &TransformationUpdate,
+    ) -> Result<TransformationResult> {
+        self.put(self.transformation_endpoint(transformation), update)
+            .await
+    }
+
+    /// Deletes a transformation by the name of a named transformation or by a transformation string.
+    pub async fn delete_transformation(
+        &self,
+        transformation: &str,
+    ) -> Result<TransformationResult> {
+        self.delete(self.transformation_endpoint(transformation))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::transformation::CropMode;
+
+    #[test]
+    fn update() {
+        assert_eq!(
+            serde_json::to_value(TransformationUpdate::default()).unwrap(),
+            serde_json::json!({})
+        );
+        assert_eq!(
+            serde_json::to_value(TransformationUpdate {
+                allowed_for_strict: Some(true),
+                unsafe_update: Some(vec![
+                    Transformations::Crop(CropMode::Fill {
+                        width: 300,
+                        height: 200,
+                        gravity: None,
+                    }),
+                    Transformations::Named("watermark".into()),
+                ]),
+            })
+            .unwrap(),
+            serde_json::json!({
+                "allowed_for_strict": true,
+                "unsafe_update": "c_fill,w_300,h_200/t_watermark"
+            })
+        );
+    }
+
+    #[test]
+    fn transformation_endpoint() {
+        let admin = Admin::new("key".into(), "cloud".into(), "secret".into());
+        assert_eq!(
+            admin.transformation_endpoint("c_fill,w_300,h_200").as_str(),
+            "https://api.cloudinary.com/v1_1/cloud/transformations?transformation=c_fill%2Cw_300%2Ch_200"
+        );
+    }
+
+    #[test]
+    fn deserialize_details() {
+        let details: TransformationDetails = serde_json::from_str(
+            r#"{
+                "name": "t_thumbnail_card",
+                "allowed_for_strict": true,
+                "used": false,
+                "named": true,
+                "info": [{"crop": "fill", "width": 300, "height": 200}],
+                "derived": []
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(details.name, "t_thumbnail_card");
+        assert_eq!(details.info[0]["crop"], "fill");
+    }
+}
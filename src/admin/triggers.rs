@@ -0,0 +1,165 @@
+use std::fmt::{Display, Formatter};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::Admin;
+
+/// Events a [`Trigger`] can notify about.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventType {
+    Upload,
+    Delete,
+    Rename,
+    Move,
+    Restore,
+    Moderation,
+    Eager,
+    Explode,
+    Multi,
+    GenerateArchive,
+    CreateFolder,
+    DeleteFolder,
+    MoveOrRenameAssetFolder,
+    AccessControlChanged,
+    ResourceTagsChanged,
+    ResourceContextChanged,
+    ResourceMetadataChanged,
+    ResourceDisplayNameChanged,
+    /// An event this version of the crate does not know about. Only returned by the API, creating a trigger with it
+    /// fails.
+    #[serde(other)]
+    Unknown,
+}
+
+impl Display for EventType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventType::Upload => write!(f, "upload"),
+            EventType::Delete => write!(f, "delete"),
+            EventType::Rename => write!(f, "rename"),
+            EventType::Move => write!(f, "move"),
+            EventType::Restore => write!(f, "restore"),
+            EventType::Moderation => write!(f, "moderation"),
+            EventType::Eager => write!(f, "eager"),
+            EventType::Explode => write!(f, "explode"),
+            EventType::Multi => write!(f, "multi"),
+            EventType::GenerateArchive => write!(f, "generate_archive"),
+            EventType::CreateFolder => write!(f, "create_folder"),
+            EventType::DeleteFolder => write!(f, "delete_folder"),
+            EventType::MoveOrRenameAssetFolder => write!(f, "move_or_rename_asset_folder"),
+            EventType::AccessControlChanged => write!(f, "access_control_changed"),
+            EventType::ResourceTagsChanged => write!(f, "resource_tags_changed"),
+            EventType::ResourceContextChanged => write!(f, "resource_context_changed"),
+            EventType::ResourceMetadataChanged => write!(f, "resource_metadata_changed"),
+            EventType::ResourceDisplayNameChanged => write!(f, "resource_display_name_changed"),
+            EventType::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// A [webhook notification](https://cloudinary.com/documentation/notifications) registered on the account.
+#[derive(Clone, Deserialize, Debug)]
+pub struct Trigger {
+    pub id: String,
+    /// The URL notified about the event.
+    pub uri: String,
+    pub event_type: EventType,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct TriggerList {
+    pub triggers: Vec<Trigger>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct TriggerResult {
+    /// `deleted` on deletion.
+    pub message: Option<String>,
+}
+
+impl Admin {
+    /// Lists the triggers of the account, optionally only the ones for the given event type.
+    ///
+    /// ```rust
+    /// # async fn triggers() {
+    /// use cloudinary::admin::{Admin, triggers::EventType};
+    ///
+    /// let admin = Admin::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string());
+    /// let triggers = admin.triggers(Some(EventType::Upload)).await;
+    /// # }
+    /// ```
+    pub async fn triggers(&self, event_type: Option<EventType>) -> Result<TriggerList> {
+        let mut url = self.endpoint(&["triggers"]);
+        if let Some(event_type) = event_type {
+            url.query_pairs_mut()
+                .append_pair("event_type", &event_type.to_string());
+        }
+        self.get(url).await
+    }
+
+    /// Registers `uri` to be notified about every event of the given type.
+    pub async fn create_trigger(&self, event_type: EventType, uri: &str) -> Result<Trigger> {
+        self.post(
+            self.endpoint(&["triggers"]),
+            &serde_json::json!({ "uri": uri, "event_type": event_type }),
+        )
+        .await
+    }
+
+    /// Changes the URL notified by the trigger with the given id.
+    pub async fn update_trigger(&self, id: &str, uri: &str) -> Result<Trigger> {
+        self.put(
+            self.endpoint(&["triggers", id]),
+            &serde_json::json!({ "new_uri": uri }),
+        )
+        .await
+    }
+
+    pub async fn delete_trigger(&self, id: &str) -> Result<TriggerResult> {
+        self.delete(self.endpoint(&["triggers", id])).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn event_type() {
+        assert_eq!(
+            serde_json::to_value(EventType::ResourceTagsChanged).unwrap(),
+            EventType::ResourceTagsChanged.to_string()
+        );
+        assert_eq!(
+            serde_json::from_str::<EventType>(r#""user_created""#).unwrap(),
+            EventType::Unknown
+        );
+    }
+
+    #[test]
+    fn deserialize_list() {
+        let list: TriggerList = serde_json::from_str(
+            r#"{
+                "triggers": [{
+                    "id": "8b8d0cc4d9f1bd0a9a2b5b3e6c2f9a1d",
+                    "product_environment_id": "e9f2a7c1",
+                    "uri": "https://example.com/hooks",
+                    "event_type": "upload",
+                    "created_at": "2024-06-25T08:41:31Z",
+                    "updated_at": "2024-06-25T08:41:31Z"
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(list.triggers[0].event_type, EventType::Upload);
+        assert_eq!(list.triggers[0].uri, "https://example.com/hooks");
+    }
+}
@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+
+use super::{Admin, Pagination};
+use crate::transformation::Image;
+
+/// Maps a folder of the account to a remote URL prefix, so files requested from the folder are
+/// [uploaded automatically](https://cloudinary.com/documentation/fetch_remote_images#auto_upload_remote_files_to_cloudinary)
+/// from the remote location.
+///
+/// ```rust
+/// use cloudinary::admin::upload_mappings::UploadMapping;
+///
+/// let mapping = UploadMapping {
+///     folder: "remote_media".into(),
+///     template: "https://example.com/media/".into(),
+/// };
+/// let image = mapping.image("cloud_name".into(), "https://example.com/media/images/dog.jpg").unwrap();
+/// assert_eq!(
+///     image.to_string(),
+///     "https://res.cloudinary.com/cloud_name/image/upload/remote_media/images/dog.jpg"
+/// );
+/// ```
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct UploadMapping {
+    pub folder: String,
+    /// The URL prefix the folder is mapped to.
+    pub template: String,
+}
+
+impl UploadMapping {
+    /// The [`Image`] delivering `remote_url` through this mapping. Returns `None` if the URL does not start with the
+    /// template of the mapping.
+    pub fn image(&self, cloud_name: Arc<str>, remote_url: &str) -> Option<Image> {
+        remote_url
+            .strip_prefix(&self.template)
+            .map(|path| Image::mapped(cloud_name, &self.folder, path))
+    }
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct UploadMappingList {
+    pub mappings: Vec<UploadMapping>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct UploadMappingResult {
+    /// `created`, `updated` or `deleted`.
+    pub message: String,
+}
+
+impl Admin {
+    fn upload_mapping_endpoint(&self, folder: &str) -> Url {
+        let mut url = self.endpoint(&["upload_mappings"]);
+        url.query_pairs_mut().append_pair("folder", folder);
+        url
+    }
+
+    /// Lists the upload mappings of the account.
+    ///
+    /// ```rust
+    /// # async fn mappings() {
+    /// use cloudinary::admin::{Admin, Pagination};
+    ///
+    /// let admin = Admin::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string());
+    /// let mappings = admin.upload_mappings(&Pagination::default()).await;
+    /// # }
+    /// ```
+    pub async fn upload_mappings(&self, pagination: &Pagination) -> Result<UploadMappingList> {
+        let mut url = self.endpoint(&["upload_mappings"]);
+        pagination.apply(&mut url);
+        self.get(url).await
+    }
+
+    pub async fn upload_mapping(&self, folder: &str) -> Result<UploadMapping> {
+        self.get(self.upload_mapping_endpoint(folder)).await
+    }
+
+    pub async fn create_upload_mapping(
+        &self,
+        mapping: &UploadMapping,
+    ) -> Result<UploadMappingResult> {
+        self.post(self.endpoint(&["upload_mappings"]), mapping)
+            .await
+    }
+
+    /// Changes the template of the mapping of `mapping.folder`.
+    pub async fn update_upload_mapping(
+        &self,
+        mapping: &UploadMapping,
+    ) -> Result<UploadMappingResult> {
+        self.put(self.endpoint(&["upload_mappings"]), mapping).await
+    }
+
+    pub async fn delete_upload_mapping(&self, folder: &str) -> Result<UploadMappingResult> {
+        self.delete(self.upload_mapping_endpoint(folder)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn upload_mapping_endpoint() {
+        let admin = Admin::new("key".into(), "cloud".into(), "secret".into());
+        assert_eq!(
+            admin.upload_mapping_endpoint("remote media").as_str(),
+            "https://api.cloudinary.com/v1_1/cloud/upload_mappings?folder=remote+media"
+        );
+    }
+
+    #[test]
+    fn image() {
+        let mapping = UploadMapping {
+            folder: "remote".into(),
+            template: "https://example.com/media/".into(),
+        };
+        assert!(
+            mapping
+                .image("test".into(), "https://example.org/a.jpg")
+                .is_none()
+        );
+        assert_eq!(
+            mapping
+                .image("test".into(), "https://example.com/media/a/b.jpg")
+                .unwrap()
+                .to_string(),
+            "https://res.cloudinary.com/test/image/upload/remote/a/b.jpg"
+        );
+    }
+
+    #[test]
+    fn deserialize_list() {
+        let list: UploadMappingList = serde_json::from_str(
+            r#"{"mappings": [{"folder": "wiki", "template": "https://upload.wikimedia.org/wikipedia/"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(list.mappings[0].folder, "wiki");
+        assert_eq!(list.next_cursor, None);
+    }
+}
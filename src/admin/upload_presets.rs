@@ -0,0 +1,373 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use anyhow::Result;
+use itertools::Itertools;
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{Admin, Pagination};
+use crate::upload::OptionalParameters;
+
+/// An [upload preset](https://cloudinary.com/documentation/upload_presets) declared in code.
+///
+/// ```rust
+/// use std::collections::BTreeSet;
+/// use cloudinary::admin::upload_presets::UploadPreset;
+/// use cloudinary::upload::OptionalParameters;
+///
+/// let preset = UploadPreset {
+///     unsigned: true,
+///     settings: BTreeSet::from([
+///         OptionalParameters::AssetFolder("avatars".into()),
+///         OptionalParameters::Overwrite(false),
+///     ]),
+///     ..UploadPreset::new("avatars")
+/// };
+/// ```
+#[derive(Debug)]
+pub struct UploadPreset {
+    pub name: String,
+    /// Allows the preset to be used for unsigned uploads.
+    pub unsigned: bool,
+    /// Prevents unsigned uploads from setting the public id.
+    pub disallow_public_id: bool,
+    pub settings: BTreeSet<OptionalParameters>,
+}
+
+/// A setting that differs between a local [`UploadPreset`] and the [`UploadPresetDetails`] of the account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SettingDifference {
+    pub key: String,
+    /// `None` when the setting is only present on the account.
+    pub local: Option<String>,
+    /// `None` when the setting is only present locally.
+    pub remote: Option<String>,
+}
+
+impl UploadPreset {
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            unsigned: false,
+            disallow_public_id: false,
+            settings: BTreeSet::new(),
+        }
+    }
+
+    /// Settings as they are sent to the API, with `key=value` pairs sorted as the order of a map is not stable.
+    fn settings_map(&self) -> BTreeMap<String, String> {
+        self.settings
+            .iter()
+            .map(|option| {
+                let (key, value) = option.get_pair();
+                match key.as_str() {
+                    "context" | "metadata" => {
+                        let value = value.split('|').sorted().join("|");
+                        (key, value)
+                    }
+                    _ => (key, value),
+                }
+            })
+            .collect()
+    }
+
+    fn body(&self) -> BTreeMap<String, Value> {
+        let mut body: BTreeMap<String, Value> = self
+            .settings
+            .iter()
+            .map(|option| {
+                let (key, value) = option.get_pair();
+                (key, Value::String(value))
+            })
+            .collect();
+        body.insert("name".into(), Value::String(self.name.clone()));
+        body.insert("unsigned".into(), Value::Bool(self.unsigned));
+        body.insert(
+            "disallow_public_id".into(),
+            Value::Bool(self.disallow_public_id),
+        );
+        body
+    }
+
+    /// Compares the settings with the ones stored on the account. Values are compared in the string form they are
+    /// sent to the API with, so list values have to be declared in the same order the account returns them. Maps like
+    /// `context` are compared as sorted `key=value` pairs. Settings the account returns in a different shape, e.g.
+    /// `eager` transformations expanded into objects, are compared as JSON and always reported as changed.
+    pub fn diff(&self, remote: &UploadPresetDetails) -> Vec<SettingDifference> {
+        let mut differences = Vec::new();
+        if self.unsigned != remote.unsigned {
+            differences.push(SettingDifference {
+                key: "unsigned".into(),
+                local: Some(self.unsigned.to_string()),
+                remote: Some(remote.unsigned.to_string()),
+            });
+        }
+        if self.disallow_public_id != remote.disallow_public_id() {
+            differences.push(SettingDifference {
+                key: "disallow_public_id".into(),
+                local: Some(self.disallow_public_id.to_string()),
+                remote: Some(remote.disallow_public_id().to_string()),
+            });
+        }
+        let local = self.settings_map();
+        let remote = remote.settings_map();
+        for key in local.keys().chain(remote.keys()).unique().sorted() {
+            let (local, remote) = (local.get(key), remote.get(key));
+            if local != remote {
+                differences.push(SettingDifference {
+                    key: key.clone(),
+                    local: local.cloned(),
+                    remote: remote.cloned(),
+                });
+            }
+        }
+        differences
+    }
+}
+
+/// <https://cloudinary.com/documentation/admin_api#get_the_details_of_a_single_upload_preset>
+#[derive(Clone, Deserialize, Debug)]
+pub struct UploadPresetDetails {
+    pub name: String,
+    pub unsigned: bool,
+    pub settings: HashMap<String, Value>,
+}
+
+impl UploadPresetDetails {
+    /// Missing on presets that were created without it.
+    fn disallow_public_id(&self) -> bool {
+        self.settings
+            .get("disallow_public_id")
+            .and_then(Value::as_bool)
+            .unwrap_or(false)
+    }
+
+    /// Settings without `disallow_public_id`, which is compared separately.
+    fn settings_map(&self) -> BTreeMap<String, String> {
+        self.settings
+            .iter()
+            .filter(|(key, value)| !value.is_null() && *key != "disallow_public_id")
+            .map(|(key, value)| {
+                let value = match value {
+                    Value::String(s) => s.clone(),
+                    Value::Array(values) => values
+                        .iter()
+                        .map(|v| match v {
+                            Value::String(s) => s.clone(),
+                            v => v.to_string(),
+                        })
+                        // Matches the separators of `OptionalParameters::get_pair`.
+                        .join(if key == "eager" { "|" } else { "," }),
+                    Value::Object(pairs) => pairs
+                        .iter()
+                        .map(|(k, v)| match v {
+                            Value::String(s) => format!("{k}={s}"),
+                            v => format!("{k}={v}"),
+                        })
+                        .sorted()
+                        .join("|"),
+                    value => value.to_string(),
+                };
+                (key.clone(), value)
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct UploadPresetList {
+    pub presets: Vec<UploadPresetDetails>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct UploadPresetResult {
+    /// `created`, `updated` or `deleted`.
+    pub message: String,
+    /// Only returned on creation.
+    pub name: Option<String>,
+}
+
+impl Admin {
+    /// Lists the upload presets of the account.
+    ///
+    /// ```rust
+    /// # async fn presets() {
+    /// use cloudinary::admin::{Admin, Pagination};
+    ///
+    /// let admin = Admin::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string());
+    /// let presets = admin.upload_presets(&Pagination::default()).await;
+    /// # }
+    /// ```
+    pub async fn upload_presets(&self, pagination: &Pagination) -> Result<UploadPresetList> {
+        let mut url = self.endpoint(&["upload_presets"]);
+        pagination.apply(&mut url);
+        self.get(url).await
+    }
+
+    pub async fn upload_preset(&self, name: &str) -> Result<UploadPresetDetails> {
+        self.get(self.endpoint(&["upload_presets", name])).await
+    }
+
+    pub async fn create_upload_preset(&self, preset: &UploadPreset) -> Result<UploadPresetResult> {
+        self.post(self.endpoint(&["upload_presets"]), &preset.body())
+            .await
+    }
+
+    /// Updates the preset with the given name. The settings are merged with the ones stored on the account, so
+    /// options removed from `preset` are not cleared.
+    pub async fn update_upload_preset(&self, preset: &UploadPreset) -> Result<UploadPresetResult> {
+        self.put(
+            self.endpoint(&["upload_presets", &preset.name]),
+            &preset.body(),
+        )
+        .await
+    }
+
+    pub async fn delete_upload_preset(&self, name: &str) -> Result<UploadPresetResult> {
+        self.delete(self.endpoint(&["upload_presets", name])).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::transformation::{ResizeMode, Transformations};
+
+    fn preset() -> UploadPreset {
+        UploadPreset {
+            unsigned: true,
+            disallow_public_id: true,
+            settings: BTreeSet::from([
+                OptionalParameters::AssetFolder("avatars".into()),
+                OptionalParameters::Overwrite(false),
+                OptionalParameters::AllowedFormats(vec!["jpg".into(), "png".into()]),
+            ]),
+            ..UploadPreset::new("avatars")
+        }
+    }
+
+    #[test]
+    fn body() {
+        assert_eq!(
+            serde_json::to_value(preset().body()).unwrap(),
+            serde_json::json!({
+                "name": "avatars",
+                "unsigned": true,
+                "disallow_public_id": true,
+                "asset_folder": "avatars",
+                "overwrite": "false",
+                "allowed_formats": "jpg,png"
+            })
+        );
+    }
+
+    #[test]
+    fn diff() {
+        let remote: UploadPresetDetails = serde_json::from_str(
+            r#"{
+                "name": "avatars",
+                "unsigned": true,
+                "settings": {
+                    "asset_folder": "avatars",
+                    "overwrite": true,
+                    "allowed_formats": ["jpg", "png"],
+                    "disallow_public_id": true,
+                    "tags": "user"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            preset().diff(&remote),
+            vec![
+                SettingDifference {
+                    key: "overwrite".into(),
+                    local: Some("false".into()),
+                    remote: Some("true".into()),
+                },
+                SettingDifference {
+                    key: "tags".into(),
+                    local: None,
+                    remote: Some("user".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn no_diff() {
+        let remote = UploadPresetDetails {
+            name: "avatars".into(),
+            unsigned: false,
+            settings: HashMap::from([("asset_folder".to_string(), Value::from("avatars"))]),
+        };
+        let local = UploadPreset {
+            settings: BTreeSet::from([OptionalParameters::AssetFolder("avatars".into())]),
+            ..UploadPreset::new("avatars")
+        };
+        assert_eq!(local.diff(&remote), vec![]);
+
+        let remote: UploadPresetDetails = serde_json::from_str(
+            r#"{
+                "name": "avatars",
+                "unsigned": false,
+                "settings": {"asset_folder": "avatars", "disallow_public_id": false}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(local.diff(&remote), vec![]);
+    }
+
+    #[test]
+    fn map_and_eager_no_diff() {
+        let remote: UploadPresetDetails = serde_json::from_str(
+            r#"{
+                "name": "avatars",
+                "unsigned": false,
+                "settings": {
+                    "context": {"alt": "avatar", "source": "signup"},
+                    "eager": ["c_limit,w_640,h_360", "t_thumb"]
+                }
+            }"#,
+        )
+        .unwrap();
+        let local = UploadPreset {
+            settings: BTreeSet::from([
+                OptionalParameters::Context(HashMap::from([
+                    ("source".to_string(), "signup".to_string()),
+                    ("alt".to_string(), "avatar".to_string()),
+                ])),
+                OptionalParameters::Eager(vec![
+                    Transformations::Resize(ResizeMode::Limit {
+                        width: 640,
+                        height: 360,
+                    }),
+                    Transformations::Named("thumb".into()),
+                ]),
+            ]),
+            ..UploadPreset::new("avatars")
+        };
+        assert_eq!(local.diff(&remote), vec![]);
+    }
+
+    #[test]
+    fn disallow_public_id_diff() {
+        let remote = UploadPresetDetails {
+            name: "avatars".into(),
+            unsigned: false,
+            settings: HashMap::from([("disallow_public_id".to_string(), Value::Bool(true))]),
+        };
+        assert_eq!(
+            UploadPreset::new("avatars").diff(&remote),
+            vec![SettingDifference {
+                key: "disallow_public_id".into(),
+                local: Some("false".into()),
+                remote: Some("true".into()),
+            }]
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use super::Admin;
+use crate::upload::result::deserialize_from_str;
+
+/// Usage of a single metered resource. Depending on the plan either `limit` and `used_percent` or `credits_usage`
+/// are returned.
+#[derive(Clone, Deserialize, Debug, PartialEq)]
+pub struct UsageMetric {
+    /// Count for transformations and objects, bytes for bandwidth and storage.
+    pub usage: f64,
+    pub limit: Option<f64>,
+    pub used_percent: Option<f64>,
+    pub credits_usage: Option<f64>,
+}
+
+impl UsageMetric {
+    /// Percentage of the limit that is used. Falls back to calculating it from `usage` and `limit` when the
+    /// response does not contain `used_percent`.
+    pub fn used_percent(&self) -> Option<f64> {
+        self.used_percent.or_else(|| match self.limit {
+            Some(limit) if limit > 0.0 => Some(self.usage / limit * 100.0),
+            _ => None,
+        })
+    }
+}
+
+/// <https://cloudinary.com/documentation/admin_api#usage>
+#[derive(Clone, Deserialize, Debug)]
+pub struct Usage {
+    pub plan: String,
+    #[serde(deserialize_with = "deserialize_from_str")]
+    pub last_updated: NaiveDate,
+    pub date_requested: Option<String>,
+    pub transformations: UsageMetric,
+    pub objects: UsageMetric,
+    pub bandwidth: UsageMetric,
+    pub storage: UsageMetric,
+    /// Only returned for credit based plans.
+    pub credits: Option<UsageMetric>,
+    pub requests: u64,
+    pub resources: u64,
+    pub derived_resources: u64,
+    /// Maximum sizes of uploaded assets, e.g. `image_max_size_bytes`.
+    #[serde(default)]
+    pub media_limits: HashMap<String, u64>,
+}
+
+impl Admin {
+    /// Loads the account usage report. Without a date the report of the current date is returned, otherwise the
+    /// date must be within the last 3 months.
+    ///
+    /// ```rust
+    /// # async fn usage() {
+    /// use cloudinary::admin::Admin;
+    ///
+    /// let admin = Admin::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string());
+    /// let usage = admin.usage(None).await;
+    /// # }
+    /// ```
+    pub async fn usage(&self, date: Option<NaiveDate>) -> Result<Usage> {
+        let mut url = self.endpoint(&["usage"]);
+        if let Some(date) = date {
+            url.query_pairs_mut()
+                .append_pair("date", &date.format("%d-%m-%Y").to_string());
+        }
+        self.get(url).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_credits_plan() {
+        let usage: Usage = serde_json::from_str(
+            r#"{
+                "plan": "Free",
+                "last_updated": "2024-06-24",
+                "date_requested": "2024-06-25T00:00:00Z",
+                "transformations": {"usage": 13, "credits_usage": 0.01},
+                "objects": {"usage": 3084},
+                "bandwidth": {"usage": 6132, "credits_usage": 0.0},
+                "storage": {"usage": 2000, "credits_usage": 0.0},
+                "credits": {"usage": 0.03, "limit": 25.0, "used_percent": 0.12},
+                "requests": 7055,
+                "resources": 110,
+                "derived_resources": 1,
+                "media_limits": {"image_max_size_bytes": 10485760, "raw_max_size_bytes": 10485760}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(usage.plan, "Free");
+        assert_eq!(
+            usage.last_updated,
+            NaiveDate::from_ymd_opt(2024, 6, 24).unwrap()
+        );
+        assert_eq!(usage.credits.unwrap().used_percent(), Some(0.12));
+        assert_eq!(usage.objects.used_percent(), None);
+        assert_eq!(usage.media_limits["image_max_size_bytes"], 10485760);
+    }
+
+    #[test]
+    fn used_percent_from_limit() {
+        let metric: UsageMetric = serde_json::from_str(r#"{"usage": 250, "limit": 1000}"#).unwrap();
+        assert_eq!(metric.used_percent(), Some(25.0));
+    }
+}
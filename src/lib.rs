@@ -50,9 +50,9 @@
 //!
 //! # Destroy an asset by publicID
 //! ```rust
-//! use cloudinary::upload::Upload;
+//! use cloudinary::upload::{DeliveryType, ResourceTypes, Upload};
 //! let upload = Upload::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string() );
-//! let result = upload.destroy("publicID");
+//! let result = upload.destroy("publicID", ResourceTypes::Image, DeliveryType::Upload, false);
 //! ```
 //!
 //! # Transform an image
@@ -61,6 +61,7 @@
 //! * Resize
 //! * Crop
 //! * Pad
+//! * Named transformations
 //!
 //! ## Resizing an image:
 //!
@@ -108,6 +109,24 @@
 //! );
 //! ```
 //!
+//! ## Delivering videos and raw files:
+//!
+//! ```rust
+//! use cloudinary::transformation::{
+//!     Transformations::Resize, ResizeMode::Limit, Raw, Video
+//! };
+//!
+//! let video = Video::new("test".into(), "path/name.mp4".into())
+//!     .add_transformation(Resize(Limit{ width: 640, height: 360 }));
+//! assert_eq!(
+//!     video.to_string(),
+//!     "https://res.cloudinary.com/test/video/upload/c_limit,w_640,h_360/path/name.mp4"
+//! );
+//!
+//! let raw = Raw::new("test".into(), "path/name.txt".into());
+//! assert_eq!(raw.to_string(), "https://res.cloudinary.com/test/raw/upload/path/name.txt");
+//! ```
+//!
 //! # Get Image from URL
 //!
 //! Unofficial api. This is not supported by Cloudinary, and can break at any time.
@@ -124,6 +143,25 @@
 //! assert_eq!(image.to_string(), "https://res.cloudinary.com/test/image/upload/path/name.png");
 //! ```
 //!
+//! # Search assets
+//! ```rust
+//! # async fn search() {
+//! use cloudinary::admin::{Admin, search::{Expression, Operator, Search, SortDirection}};
+//! use cloudinary::upload::ResourceTypes;
+//!
+//! let admin = Admin::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string());
+//! let search = Search::new()
+//!     .expression(
+//!         Expression::ResourceType(ResourceTypes::Image)
+//!             .and(Expression::Tag("shoes".into()))
+//!             .and(Expression::Bytes(Operator::Greater, 1_000_000)),
+//!     )
+//!     .sort_by("created_at", SortDirection::Desc)
+//!     .max_results(100);
+//! let result = admin.search(&search).await;
+//! # }
+//! ```
+//!
 //! # Get a list of all assets with a given tag
 //! ```rust
 //! # async fn tags(){
@@ -153,6 +191,7 @@
 //!
 //! The minimum supported Rust version for this crate is 1.65
 //!
+pub mod admin;
 pub mod tags;
 pub mod transformation;
 pub mod upload;
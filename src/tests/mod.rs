@@ -2,9 +2,14 @@ use dotenv::dotenv;
 use pretty_assertions::assert_eq;
 use std::{collections::BTreeSet, env::var};
 
+use crate::admin::{
+    Admin, Pagination,
+    search::{Expression, Operator, Search},
+};
 use crate::upload::{
-    OptionalParameters, Source, Upload,
+    DeliveryType, OptionalParameters, ResourceTypes, Source, Upload,
     UploadResult::{Error, Response, ResponseWithImageMetadata},
+    result::DestroyResult,
 };
 
 fn env() -> (String, String, String) {
@@ -135,9 +140,23 @@ async fn test_destroy_non_existing_asset() {
     let cloudinary = Upload::new(api_key, cloud_name, api_secret);
     let public_id = "random-1239290r29-does-it-exists-3we97pcsdlncdsa";
 
-    let res = cloudinary.destroy(public_id).await.unwrap();
+    let res = cloudinary
+        .destroy(public_id, ResourceTypes::Image, DeliveryType::Upload, false)
+        .await
+        .unwrap();
 
-    assert_eq!(res.result, "not found")
+    assert_eq!(res, DestroyResult::NotFound)
+}
+
+#[tokio::test]
+async fn test_destroy_auto_resource_type() {
+    let cloudinary = Upload::new("api_key".into(), "cloud_name".into(), "api_secret".into());
+
+    let res = cloudinary
+        .destroy("image", ResourceTypes::Auto, DeliveryType::Upload, false)
+        .await;
+
+    assert!(res.is_err())
 }
 
 #[tokio::test]
@@ -160,8 +179,11 @@ async fn test_destroy_existing_asset() {
 
     match res {
         Response(_) => {
-            let res = cloudinary.destroy(public_id).await.unwrap();
-            assert_eq!(res.result, "ok")
+            let res = cloudinary
+                .destroy(public_id, ResourceTypes::Image, DeliveryType::Upload, true)
+                .await
+                .unwrap();
+            assert_eq!(res, DestroyResult::Ok)
         }
         Error(err) => panic!("{}", err.error.message),
         _ => {
@@ -270,3 +292,46 @@ async fn test_image_upload_from_new_acc_with_options_out_of_alpha_order() {
         ),
     }
 }
+
+#[tokio::test]
+async fn test_search_by_public_id() {
+    let (api_key, cloud_name, api_secret) = env();
+    let admin = Admin::new(api_key, cloud_name, api_secret);
+    let public_id = "image_upload_from_url";
+
+    let res = admin
+        .search(
+            &Search::new()
+                .expression(Expression::Field(
+                    "public_id".into(),
+                    Operator::Equal,
+                    public_id.into(),
+                ))
+                .max_results(1),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.resources[0].public_id, public_id);
+}
+
+#[tokio::test]
+async fn test_create_and_delete_folder() {
+    let (api_key, cloud_name, api_secret) = env();
+    let admin = Admin::new(api_key, cloud_name, api_secret);
+    let parent = format!("folder_{}", chrono::Utc::now().timestamp_micros());
+    let path = format!("{}/child", parent);
+
+    let created = admin.create_folder(&path).await.unwrap();
+    assert!(created.success);
+    assert_eq!(created.path, path);
+
+    let sub_folders = admin
+        .sub_folders(&parent, &Pagination::default())
+        .await
+        .unwrap();
+    assert_eq!(sub_folders.folders[0].path, path);
+
+    let deleted = admin.delete_folder(&parent).await.unwrap();
+    assert!(deleted.deleted.contains(&parent));
+}
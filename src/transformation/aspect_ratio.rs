@@ -1,6 +1,11 @@
-use std::fmt::{Display, Formatter};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
 
-#[derive(Debug, Clone)]
+use super::parse::{ParseError, number};
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum AspectRatio {
     /// Ignore the aspect ratio of the input and stretch to exactly the given width or height values.
     Ignore,
@@ -19,3 +24,49 @@ impl Display for AspectRatio {
         }
     }
 }
+
+impl FromStr for AspectRatio {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "fl_ignore_aspect_ratio" {
+            return Ok(AspectRatio::Ignore);
+        }
+        let ratio = s
+            .strip_prefix("ar_")
+            .ok_or_else(|| ParseError::new(s, "expected an aspect ratio"))?;
+        match ratio.split_once(':') {
+            Some((width, height)) => match (number(width), number(height)) {
+                (Some(width), Some(height)) => Ok(AspectRatio::Sides(width, height)),
+                _ => Err(ParseError::new(s, "expected sides like 16:9")),
+            },
+            // Display never writes signs or exponents.
+            None if !ratio.bytes().all(|b| b.is_ascii_digit() || b == b'.') => {
+                Err(ParseError::new(s, "expected a positive number"))
+            }
+            None => match ratio.parse::<f32>() {
+                Ok(result) if result.is_finite() && result > 0.0 => Ok(AspectRatio::Result(result)),
+                _ => Err(ParseError::new(s, "expected a positive number")),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AspectRatio;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn from_str() {
+        assert_eq!("ar_16:9".parse(), Ok(AspectRatio::Sides(16, 9)));
+        assert_eq!("ar_1.5".parse(), Ok(AspectRatio::Result(1.5)));
+        assert_eq!("fl_ignore_aspect_ratio".parse(), Ok(AspectRatio::Ignore));
+        assert_eq!(
+            "ar_16:x".parse::<AspectRatio>().unwrap_err().to_string(),
+            "invalid transformation parameter `ar_16:x`: expected sides like 16:9"
+        );
+        assert!("ar_+16:9".parse::<AspectRatio>().is_err());
+        assert!("ar_1e2".parse::<AspectRatio>().is_err());
+    }
+}
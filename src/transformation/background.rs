@@ -1,8 +1,11 @@
-use std::fmt::{Display, Formatter};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
 
-use super::named_color::NamedColor;
+use super::{named_color::NamedColor, parse::ParseError};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AutoModes {
     /// Selects the predominant color, taking only the image border pixels into account.
     Border,
@@ -40,7 +43,25 @@ impl Display for AutoModes {
     }
 }
 
-#[derive(Debug, Clone)]
+impl FromStr for AutoModes {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "border" => Ok(AutoModes::Border),
+            "predominant" => Ok(AutoModes::Predominant),
+            "border_contrast" => Ok(AutoModes::BorderContrast),
+            "predominant_contrast" => Ok(AutoModes::PredominantContrast),
+            "predominant_gradient" => Ok(AutoModes::PredominantGradient),
+            "predominant_gradient_contrast" => Ok(AutoModes::PredominantGradientContrast),
+            "border_gradient" => Ok(AutoModes::BorderGradient),
+            "border_gradient_contrast" => Ok(AutoModes::BorderGradientContrast),
+            _ => Err(ParseError::new(s, "unknown auto mode")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Number {
     Two,
     Four,
@@ -55,7 +76,19 @@ impl Display for Number {
     }
 }
 
-#[derive(Debug, Clone)]
+impl FromStr for Number {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "2" => Ok(Number::Two),
+            "4" => Ok(Number::Four),
+            _ => Err(ParseError::new(s, "expected 2 or 4 colors")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Direction {
     Horizontal,
     Vertical,
@@ -74,7 +107,21 @@ impl Display for Direction {
     }
 }
 
-#[derive(Debug, Clone)]
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "horizontal" => Ok(Direction::Horizontal),
+            "vertical" => Ok(Direction::Vertical),
+            "diagonal_desc" => Ok(Direction::DiagonalDesc),
+            "diagonal_asc" => Ok(Direction::DiagonalAsc),
+            _ => Err(ParseError::new(s, "unknown direction")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Color {
     Named(NamedColor),
     RGB(u8, u8, u8),
@@ -91,13 +138,39 @@ impl Display for Color {
     }
 }
 
+impl FromStr for Color {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(hex) = s.strip_prefix("rgb:") else {
+            return s.parse().map(Color::Named);
+        };
+        // `from_str_radix` accepts a leading `+`, which Display never writes.
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ParseError::new(s, "expected 6 or 8 hex digits"));
+        }
+        let channels = (0..hex.len())
+            .step_by(2)
+            .map(|i| {
+                hex.get(i..i + 2)
+                    .and_then(|c| u8::from_str_radix(c, 16).ok())
+            })
+            .collect::<Option<Vec<u8>>>();
+        match channels.as_deref() {
+            Some(&[r, g, b]) => Ok(Color::RGB(r, g, b)),
+            Some(&[r, g, b, a]) => Ok(Color::RGBA(r, g, b, a)),
+            _ => Err(ParseError::new(s, "expected 6 or 8 hex digits")),
+        }
+    }
+}
+
 impl From<NamedColor> for Color {
     fn from(color: NamedColor) -> Self {
         Color::Named(color)
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Auto {
     /// The method to use for determining the solid or gradient color(s) to apply.
     /// Default: `AutoModes::Border`
@@ -140,11 +213,64 @@ impl Display for Auto {
     }
 }
 
+impl FromStr for Auto {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The palette is always the last option and its colors may contain colons themselves.
+        let (options, palette) = match s.split_once(":palette_") {
+            Some((options, palette)) => (options, Some(palette)),
+            None => (s, None),
+        };
+        let mut options = options.split(':');
+        if options.next() != Some("auto") {
+            return Err(ParseError::new(s, "expected auto"));
+        }
+        let mut auto = Auto {
+            mode: None,
+            number: None,
+            direction: None,
+            palette: None,
+        };
+        for option in options {
+            if auto.mode.is_none() && auto.number.is_none() && auto.direction.is_none() {
+                if let Ok(mode) = option.parse() {
+                    auto.mode = Some(mode);
+                    continue;
+                }
+            }
+            if auto.number.is_none() && auto.direction.is_none() {
+                if let Ok(number) = option.parse() {
+                    auto.number = Some(number);
+                    continue;
+                }
+            }
+            if auto.direction.is_none() {
+                if let Ok(direction) = option.parse() {
+                    auto.direction = Some(direction);
+                    continue;
+                }
+            }
+            return Err(ParseError::new(option, "unexpected auto option"));
+        }
+        if let Some(palette) = palette {
+            auto.palette = Some(match palette {
+                "" => Vec::new(),
+                palette => palette
+                    .split('_')
+                    .map(str::parse)
+                    .collect::<Result<_, _>>()?,
+            });
+        }
+        Ok(auto)
+    }
+}
+
 /// Applies a background to empty or transparent areas.
 ///
 /// Can also be used as a qualifier to override the default background color for padded cropping, text overlays and
 /// generated waveform images.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Background {
     Color(Color),
     Auto(Auto),
@@ -177,6 +303,24 @@ impl Display for Background {
     }
 }
 
+impl FromStr for Background {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("b_") {
+            Some(auto) if auto == "auto" || auto.starts_with("auto:") => auto
+                .parse()
+                .map(Background::Auto)
+                .map_err(|e: ParseError| e.in_parameter(s)),
+            Some(color) => color
+                .parse()
+                .map(Background::Color)
+                .map_err(|e: ParseError| e.in_parameter(s)),
+            None => Err(ParseError::new(s, "expected a background")),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::Auto;
@@ -186,6 +330,7 @@ mod test {
     use crate::transformation::{
         background::{Background, Color},
         named_color::NamedColor,
+        pad_mode::PadMode,
     };
 
     #[test]
@@ -411,4 +556,57 @@ mod test {
             "b_auto:predominant_gradient_contrast"
         );
     }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            "b_rgb:0a646e6f".parse(),
+            Ok(Background::Color(Color::RGBA(10, 100, 110, 111)))
+        );
+        assert_eq!(
+            "b_auto:border_gradient:4:palette_black_rgb:020aff".parse(),
+            Ok(Background::Auto(Auto {
+                mode: Some(AutoModes::BorderGradient),
+                number: Some(Number::Four),
+                direction: None,
+                palette: Some(vec![NamedColor::Black.into(), Color::RGB(2, 10, 255)]),
+            }))
+        );
+        assert_eq!(
+            "b_rgb:0a6".parse::<Background>().unwrap_err().parameter(),
+            "b_rgb:0a6"
+        );
+        assert_eq!(
+            "b_rgb:+f+f+f"
+                .parse::<Background>()
+                .unwrap_err()
+                .parameter(),
+            "b_rgb:+f+f+f"
+        );
+        assert_eq!(
+            "b_auto:border:3"
+                .parse::<Background>()
+                .unwrap_err()
+                .parameter(),
+            "b_auto:border:3"
+        );
+        assert_eq!(
+            "b_auto:border:3"
+                .parse::<Background>()
+                .unwrap_err()
+                .to_string(),
+            "invalid transformation parameter `b_auto:border:3`: unexpected auto option"
+        );
+        assert_eq!(
+            "c_pad,b_blak,w_100"
+                .parse::<PadMode>()
+                .unwrap_err()
+                .parameter(),
+            "b_blak"
+        );
+        assert_eq!(
+            "rgb:0a6".parse::<Color>().unwrap_err().parameter(),
+            "rgb:0a6"
+        );
+    }
 }
@@ -1,8 +1,12 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
-use super::{aspect_ratio::AspectRatio, gravity::Gravity};
+use super::{
+    aspect_ratio::AspectRatio,
+    gravity::Gravity,
+    parse::{Components, Key, ParseError},
+};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum CropMode {
     /// Creates an asset with the exact specified width and AspectRatio without distorting the asset. This option first
     /// scales as much as needed to fill the specified dimensions. If the requested aspect ratio is
@@ -81,6 +85,40 @@ impl Display for CropMode {
     }
 }
 
+impl FromStr for CropMode {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let components = Components::parse(s)?;
+        if components.crop()? != "fill" {
+            return Err(components.unexpected_crop());
+        }
+        components.allow(&[
+            Key::Crop,
+            Key::Width,
+            Key::Height,
+            Key::AspectRatio,
+            Key::Gravity,
+        ])?;
+        let ar = components.aspect_ratio()?;
+        let gravity = components.gravity()?;
+        match (components.width()?, components.height()?, ar) {
+            (Some(width), None, ar) => Ok(CropMode::FillByWidth { width, ar, gravity }),
+            (None, Some(height), ar) => Ok(CropMode::FillByHeight {
+                height,
+                ar,
+                gravity,
+            }),
+            (Some(width), Some(height), None) => Ok(CropMode::Fill {
+                width,
+                height,
+                gravity,
+            }),
+            _ => Err(components.unexpected_dimensions()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -171,4 +209,34 @@ mod tests {
             "c_fill,g_auto:classic,w_100,h_100"
         );
     }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "c_fill,g_auto:classic,w_100,h_100".parse(),
+            Ok(CropMode::Fill {
+                width: 100,
+                height: 100,
+                gravity: Some(Gravity::AutoClassic),
+            })
+        );
+        assert_eq!(
+            "c_fill,g_top,w_100"
+                .parse::<CropMode>()
+                .unwrap_err()
+                .parameter(),
+            "g_top"
+        );
+        assert_eq!(
+            "c_fill,b_black,w_100"
+                .parse::<CropMode>()
+                .unwrap_err()
+                .parameter(),
+            "b_black"
+        );
+        assert_eq!(
+            "c_pad,w_100".parse::<CropMode>().unwrap_err().parameter(),
+            "c_pad"
+        );
+    }
 }
@@ -1,8 +1,10 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
+
+use super::parse::ParseError;
 
 /// A qualifier that determines which part of an asset to focus on, and thus which part of the asset to keep, when any
 /// part of the asset is cropped. For overlays, this setting determines where to place the overlay.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Gravity {
     /// The compass direction represents a location in the asset, such as the top-right corner.
     NorthEast,
@@ -65,6 +67,8 @@ pub enum Gravity {
     /// Detect all text elements in an image using the OCR Text Detection and Extraction add-on and use the detected
     /// bounding box coordinates as the basis of the transformation.
     OcrText,
+    /// Automatically focuses on the most interesting regions of the asset.
+    Auto,
     /// Applies deep-learning algorithms to identify the subjects of an image that are most likely to attract a
     /// person's gaze.
     AutoSubject,
@@ -99,12 +103,50 @@ impl Display for Gravity {
             Gravity::FacesCenter => write!(f, "g_faces:center"),
             Gravity::FacesAuto => write!(f, "g_faces:auto"),
             Gravity::OcrText => write!(f, "g_ocr_text"),
+            Gravity::Auto => write!(f, "g_auto"),
             Gravity::AutoSubject => write!(f, "g_auto:subject"),
             Gravity::AutoClassic => write!(f, "g_auto:classic"),
         }
     }
 }
 
+impl FromStr for Gravity {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "g_north_east" => Ok(Gravity::NorthEast),
+            "g_north" => Ok(Gravity::North),
+            "g_north_west" => Ok(Gravity::NorthWest),
+            "g_west" => Ok(Gravity::West),
+            "g_south_west" => Ok(Gravity::SouthWest),
+            "g_south" => Ok(Gravity::South),
+            "g_south_east" => Ok(Gravity::SouthEast),
+            "g_east" => Ok(Gravity::East),
+            "g_center" => Ok(Gravity::Center),
+            "g_adv_eyes" => Ok(Gravity::AdvEyes),
+            "g_adv_face" => Ok(Gravity::AdvFace),
+            "g_adv_faces" => Ok(Gravity::AdvFaces),
+            "g_custom" => Ok(Gravity::Custom),
+            "g_custom:face" => Ok(Gravity::CustomFace),
+            "g_custom:adv_face" => Ok(Gravity::CustomAdvFace),
+            "g_custom:adv_faces" => Ok(Gravity::CustomAdvFaces),
+            "g_custom:faces" => Ok(Gravity::CustomFaces),
+            "g_face" => Ok(Gravity::Face),
+            "g_face:center" => Ok(Gravity::FaceCenter),
+            "g_face:auto" => Ok(Gravity::FaceAuto),
+            "g_faces" => Ok(Gravity::Faces),
+            "g_faces:center" => Ok(Gravity::FacesCenter),
+            "g_faces:auto" => Ok(Gravity::FacesAuto),
+            "g_ocr_text" => Ok(Gravity::OcrText),
+            "g_auto" => Ok(Gravity::Auto),
+            "g_auto:subject" => Ok(Gravity::AutoSubject),
+            "g_auto:classic" => Ok(Gravity::AutoClassic),
+            _ => Err(ParseError::new(s, "unknown gravity")),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::transformation::gravity::Gravity;
@@ -135,6 +177,7 @@ mod tests {
         assert_eq!(Gravity::FacesCenter.to_string(), "g_faces:center");
         assert_eq!(Gravity::FacesAuto.to_string(), "g_faces:auto");
         assert_eq!(Gravity::OcrText.to_string(), "g_ocr_text");
+        assert_eq!(Gravity::Auto.to_string(), "g_auto");
         assert_eq!(Gravity::AutoSubject.to_string(), "g_auto:subject");
         assert_eq!(Gravity::AutoClassic.to_string(), "g_auto:classic");
     }
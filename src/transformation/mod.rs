@@ -1,28 +1,53 @@
 pub mod aspect_ratio;
+pub mod auth_token;
 pub mod background;
 pub mod crop_mode;
 pub mod gravity;
 pub mod named_color;
 pub mod pad_mode;
+pub mod page;
+mod parse;
+#[cfg(test)]
+mod proptests;
 pub mod resize_mode;
+mod signature;
 
 use std::{
     cell::RefCell,
     fmt::{Display, Formatter},
+    marker::PhantomData,
+    str::FromStr,
     sync::Arc,
 };
 
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
 use url::Url;
 
-pub use self::{crop_mode::CropMode, pad_mode::PadMode, resize_mode::ResizeMode};
+use crate::upload::DeliveryType;
+
+use self::{
+    auth_token::AuthToken,
+    parse::Components,
+    signature::{UrlSigner, is_signature},
+};
+pub use self::{
+    crop_mode::CropMode, pad_mode::PadMode, page::Page, parse::ParseError, resize_mode::ResizeMode,
+    signature::SignatureAlgorithm,
+};
 
 #[non_exhaustive]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Transformations {
     /// These modes adjust the size of the delivered image without cropping out any elements of the original image.
     Resize(ResizeMode),
     Crop(CropMode),
     Pad(PadMode),
+    /// A [named transformation](https://cloudinary.com/documentation/image_transformations#named_transformations)
+    /// defined on the account, referenced by its name without the `t_` prefix.
+    Named(String),
+    Page(Page),
+    /// A transformation component kept as it is, e.g. when it was parsed from a URL.
+    Raw(String),
 }
 
 impl Display for Transformations {
@@ -31,28 +56,179 @@ impl Display for Transformations {
             Transformations::Resize(resize_mode) => write!(f, "{}", resize_mode),
             Transformations::Crop(crop_mode) => write!(f, "{}", crop_mode),
             Transformations::Pad(pad_mode) => write!(f, "{}", pad_mode),
+            Transformations::Named(name) => write!(f, "t_{}", name),
+            Transformations::Page(page) => write!(f, "{}", page),
+            Transformations::Raw(component) => write!(f, "{}", component),
         }
     }
 }
 
+/// Parses a transformation from its string representation, the inverse of [`Display`]. Named transformations and
+/// pages are recognized by their prefix, everything else by its crop mode. Never returns [`Transformations::Raw`].
+///
+/// # Example:
+/// ```rust
+/// use cloudinary::transformation::{
+///     CropMode, Transformations, aspect_ratio::AspectRatio, gravity::Gravity,
+/// };
+/// let transformation: Transformations = "ar_16:9,c_fill,g_auto,w_400".parse().unwrap();
+/// assert_eq!(
+///     transformation,
+///     Transformations::Crop(CropMode::FillByWidth {
+///         width: 400,
+///         ar: Some(AspectRatio::Sides(16, 9)),
+///         gravity: Some(Gravity::Auto),
+///     })
+/// );
+///
+/// let error = "c_fill,w_abc".parse::<Transformations>().unwrap_err();
+/// assert_eq!(error.parameter(), "w_abc");
+/// ```
+impl FromStr for Transformations {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(name) = s.strip_prefix("t_") {
+            if name.is_empty() || name.contains([',', '/']) {
+                return Err(ParseError::new(s, "invalid transformation name"));
+            }
+            return Ok(Transformations::Named(name.to_string()));
+        }
+        if s.starts_with("pg_") {
+            return s.parse().map(Transformations::Page);
+        }
+        let components = Components::parse(s)?;
+        match components.crop()? {
+            "fill" => s.parse().map(Transformations::Crop),
+            "pad" => s.parse().map(Transformations::Pad),
+            "scale" | "limit" => s.parse().map(Transformations::Resize),
+            _ => Err(components.unexpected_crop()),
+        }
+    }
+}
+
+/// Characters of remote sources that are kept as they are in fetch URLs.
+const FETCH_SOURCE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'-')
+    .remove(b'/')
+    .remove(b':');
+
+/// Kind of asset an [`Asset`] delivery URL is built for.
+pub trait AssetKind {
+    /// The resource type segment of delivery URLs.
+    const RESOURCE_TYPE: &'static str;
+}
+
+/// Asset kinds that support [`Transformations`].
+pub trait TransformableKind: AssetKind {}
+
+#[derive(Debug, Clone)]
+pub struct ImageKind;
+
+impl AssetKind for ImageKind {
+    const RESOURCE_TYPE: &'static str = "image";
+}
+
+impl TransformableKind for ImageKind {}
+
+#[derive(Debug, Clone)]
+pub struct VideoKind;
+
+impl AssetKind for VideoKind {
+    const RESOURCE_TYPE: &'static str = "video";
+}
+
+impl TransformableKind for VideoKind {}
+
+/// Raw files are delivered as they are, so they can not be transformed.
 #[derive(Debug, Clone)]
-pub struct Image {
+pub struct RawKind;
+
+impl AssetKind for RawKind {
+    const RESOURCE_TYPE: &'static str = "raw";
+}
+
+pub type Image = Asset<ImageKind>;
+
+/// # Example:
+/// ```rust
+/// use cloudinary::transformation::{ResizeMode, Transformations, Video};
+/// let mut video = Video::new("cloud_name".into(), "intro".into()).add_transformation(
+///     Transformations::Resize(ResizeMode::Limit { width: 640, height: 360 }),
+/// );
+/// video.set_format("mp4");
+/// assert_eq!(
+///     video.to_string(),
+///     "https://res.cloudinary.com/cloud_name/video/upload/c_limit,w_640,h_360/intro.mp4"
+/// );
+/// ```
+pub type Video = Asset<VideoKind>;
+
+/// The public id of raw files includes the file extension.
+///
+/// # Example:
+/// ```rust
+/// use cloudinary::transformation::Raw;
+/// let raw = Raw::new("cloud_name".into(), "docs/terms.txt".into());
+/// assert_eq!(
+///     raw.to_string(),
+///     "https://res.cloudinary.com/cloud_name/raw/upload/docs/terms.txt"
+/// );
+/// ```
+pub type Raw = Asset<RawKind>;
+
+/// Delivery URL of an asset, use the [`Image`], [`Video`] and [`Raw`] aliases.
+#[derive(Debug, Clone)]
+pub struct Asset<K> {
     cloud_name: Arc<str>,
     public_id: Arc<str>,
+    delivery_type: DeliveryType,
+    version: Option<u64>,
     format: Option<Arc<str>>,
     transformations: RefCell<Vec<Transformations>>,
+    signer: Option<UrlSigner>,
+    auth_token: Option<AuthToken>,
+    /// The query of a parsed URL, e.g. the `_a` analytics parameter of the official SDKs.
+    query: Option<Arc<str>>,
+    kind: PhantomData<K>,
 }
 
-impl Image {
+impl<K: AssetKind> Asset<K> {
     pub fn new(cloud_name: Arc<str>, public_id: Arc<str>) -> Self {
-        Image {
+        Asset {
             cloud_name,
             public_id,
+            delivery_type: DeliveryType::Upload,
+            version: None,
             format: None,
             transformations: RefCell::new(Vec::new()),
+            signer: None,
+            auth_token: None,
+            query: None,
+            kind: PhantomData,
         }
     }
 
+    pub fn set_delivery_type(&mut self, delivery_type: DeliveryType) {
+        self.delivery_type = delivery_type;
+    }
+
+    pub fn get_delivery_type(&self) -> &DeliveryType {
+        &self.delivery_type
+    }
+
+    /// The version of the asset returned on upload. Delivering a specific version bypasses cached copies of
+    /// previous versions on the CDN.
+    pub fn set_version(&mut self, version: u64) {
+        self.version = Some(version);
+    }
+
+    pub fn get_version(&self) -> Option<u64> {
+        self.version
+    }
+
     pub fn set_format(&mut self, format: &str) {
         self.format = Some(format.into());
     }
@@ -61,8 +237,39 @@ impl Image {
         self.format.clone()
     }
 
-    pub fn add_transformation(self, transformation: Transformations) -> Self {
-        self.transformations.borrow_mut().push(transformation);
+    /// Signs the URL with the api_secret, as required for accounts with
+    /// [strict transformations](https://cloudinary.com/documentation/control_access_to_media#strict_transformations).
+    /// Ignored when an [`AuthToken`] is set, token authentication replaces the URL signature.
+    ///
+    /// # Example:
+    /// ```rust
+    /// use cloudinary::transformation::{Image, SignatureAlgorithm};
+    /// let mut image = Image::new("cloud_name".into(), "sample".into())
+    ///     .sign("api_secret", SignatureAlgorithm::Sha1);
+    /// image.set_format("jpg");
+    /// assert_eq!(
+    ///     image.build().as_str(),
+    ///     "https://res.cloudinary.com/cloud_name/image/upload/s--qWHq1LZ6--/sample.jpg"
+    /// );
+    /// ```
+    pub fn sign(mut self, api_secret: &str, algorithm: SignatureAlgorithm) -> Self {
+        self.signer = Some(UrlSigner::new(api_secret, algorithm));
+        self
+    }
+
+    /// Appends a `__cld_token__` to the URL. Without an ACL the token is generated for the path of the URL, which does
+    /// not include a signature even if the asset is [signed](Asset::sign).
+    ///
+    /// # Example:
+    /// ```rust
+    /// use chrono::TimeDelta;
+    /// use cloudinary::transformation::{Image, auth_token::AuthToken};
+    /// let image = Image::new("cloud_name".into(), "sample".into())
+    ///     .with_auth_token(AuthToken::new("00112233FF99", TimeDelta::minutes(5)).unwrap());
+    /// assert!(image.build().query().unwrap().starts_with("__cld_token__=exp="));
+    /// ```
+    pub fn with_auth_token(mut self, auth_token: AuthToken) -> Self {
+        self.auth_token = Some(auth_token);
         self
     }
 
@@ -84,43 +291,146 @@ impl Image {
             .map(|t| t.to_string())
             .collect::<Vec<String>>()
             .join("/");
-        let path = format!(
-            "{}/image/upload/{}{}",
-            self.cloud_name,
-            if transformations.is_empty() {
-                "".into()
-            } else {
-                format!("{}/", transformations)
-            },
-            self.public_id
-        );
-
-        match self.get_format() {
-            Some(format) => {
-                let file_name = self.public_id.split('/').next_back().unwrap().to_string();
-
-                let new_file_name = format!("{}.{}", file_name, format);
-                url.set_path(
-                    path.replace(file_name.as_str(), new_file_name.as_str())
-                        .as_str(),
-                );
-            }
-            None => {
-                url.set_path(path.as_str());
-            }
+        let source = match self.get_format() {
+            Some(format) => format!("{}.{}", self.public_id, format),
+            None => self.public_id.to_string(),
+        };
+        // The version is not part of the signed path.
+        let signed = [transformations.as_str(), source.as_str()]
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<&str>>()
+            .join("/");
+        let versioned = [
+            transformations,
+            self.version.map(|v| format!("v{}", v)).unwrap_or_default(),
+            source,
+        ]
+        .into_iter()
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<String>>()
+        .join("/");
+        // Token authentication replaces the URL signature, as in the official SDKs.
+        let signer = self.signer.as_ref().filter(|_| self.auth_token.is_none());
+        let path = match signer {
+            Some(signer) => format!(
+                "{}/{}/{}/{}/{}",
+                self.cloud_name,
+                K::RESOURCE_TYPE,
+                self.delivery_type,
+                signer.component(&signed),
+                versioned
+            ),
+            None => format!(
+                "{}/{}/{}/{}",
+                self.cloud_name,
+                K::RESOURCE_TYPE,
+                self.delivery_type,
+                versioned
+            ),
+        };
+        url.set_path(path.as_str());
+        let token = self
+            .auth_token
+            .as_ref()
+            .map(|auth_token| auth_token.generate(Some(url.path())));
+        let query = [self.query.as_deref(), token.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<&str>>()
+            .join("&");
+        if !query.is_empty() {
+            url.set_query(Some(&query));
         }
 
         url
     }
 }
 
-impl From<Image> for Url {
-    fn from(image: Image) -> Self {
+impl<K: TransformableKind> Asset<K> {
+    pub fn add_transformation(self, transformation: Transformations) -> Self {
+        self.transformations.borrow_mut().push(transformation);
+        self
+    }
+}
+
+impl Image {
+    /// An image delivered through an [upload mapping](https://cloudinary.com/documentation/fetch_remote_images#auto_upload_remote_files_to_cloudinary)
+    /// of `folder`. `path` is the location of the file relative to the URL prefix the folder is mapped to and
+    /// becomes the rest of the public id.
+    ///
+    /// # Example:
+    /// ```rust
+    /// use cloudinary::transformation::Image;
+    /// let image = Image::mapped("cloud_name".into(), "remote_media", "/images/dog.jpg");
+    /// assert_eq!(
+    ///     image.to_string(),
+    ///     "https://res.cloudinary.com/cloud_name/image/upload/remote_media/images/dog.jpg"
+    /// );
+    /// ```
+    pub fn mapped(cloud_name: Arc<str>, folder: &str, path: &str) -> Self {
+        Image::new(
+            cloud_name,
+            format!(
+                "{}/{}",
+                folder.trim_end_matches('/'),
+                path.trim_start_matches('/')
+            )
+            .into(),
+        )
+    }
+
+    /// A remote image delivered through Cloudinary. The format of fetched images is determined by the remote
+    /// source, use a transformation to convert it instead of [`Image::set_format`].
+    ///
+    /// # Example:
+    /// ```rust
+    /// use cloudinary::transformation::Image;
+    /// let image = Image::fetch("cloud_name".into(), "https://example.com/dog.jpg?size=large");
+    /// assert_eq!(
+    ///     image.to_string(),
+    ///     "https://res.cloudinary.com/cloud_name/image/fetch/https://example.com/dog.jpg%3Fsize%3Dlarge"
+    /// );
+    /// ```
+    pub fn fetch(cloud_name: Arc<str>, source: &str) -> Self {
+        let mut image = Image::new(
+            cloud_name,
+            utf8_percent_encode(source, FETCH_SOURCE).to_string().into(),
+        );
+        image.set_delivery_type(DeliveryType::Fetch);
+        image
+    }
+
+    /// The URL of a single page of a multi-page asset. The page is selected before any other transformation is
+    /// applied.
+    ///
+    /// # Example:
+    /// ```rust
+    /// use cloudinary::transformation::Image;
+    /// let mut image = Image::new("cloud_name".into(), "brochure".into());
+    /// image.set_format("jpg");
+    /// assert_eq!(
+    ///     image.page_url(2).as_str(),
+    ///     "https://res.cloudinary.com/cloud_name/image/upload/pg_2/brochure.jpg"
+    /// );
+    /// ```
+    pub fn page_url(&self, page: u32) -> Url {
+        let image = self.clone();
+        image
+            .transformations
+            .borrow_mut()
+            .insert(0, Transformations::Page(Page::Number(page)));
         image.build()
     }
 }
 
-impl Display for Image {
+impl<K: AssetKind> From<Asset<K>> for Url {
+    fn from(asset: Asset<K>) -> Self {
+        asset.build()
+    }
+}
+
+impl<K: AssetKind> Display for Asset<K> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.build())
     }
@@ -143,10 +453,10 @@ fn is_version(s: &str) -> bool {
     s.starts_with('v') && s.len() == 11 && s[1..].chars().all(|c| c.is_ascii_digit())
 }
 
-/// Parse a URL to an Image
+/// Parse a URL to an Asset, the resource type of the URL has to match the asset kind.
 /// Unofficial. Can break at any time.
-/// Official recommendation is to use public_id that you get after uploading an image to Cloudinary.
-impl TryFrom<Url> for Image {
+/// Official recommendation is to use public_id that you get after uploading an asset to Cloudinary.
+impl<K: AssetKind> TryFrom<Url> for Asset<K> {
     type Error = &'static str;
 
     fn try_from(url: Url) -> Result<Self, Self::Error> {
@@ -155,6 +465,9 @@ impl TryFrom<Url> for Image {
         }
 
         let mut cloud_name: Option<&str> = None;
+        let mut delivery_type = DeliveryType::Upload;
+        let mut version: Option<u64> = None;
+        let mut transformations: Vec<Transformations> = Vec::new();
         let mut public_id_parts: Vec<(&str, Option<&str>)> = Vec::new();
         let mut public_id_territory = false;
         for (pos, s) in url.path_segments().unwrap().enumerate() {
@@ -163,31 +476,26 @@ impl TryFrom<Url> for Image {
                     cloud_name = Some(s);
                 }
                 1 => {
-                    if s != "image" {
-                        return Err("Only image is supported");
+                    if s != K::RESOURCE_TYPE {
+                        return Err("Resource type does not match");
                     }
                 }
                 2 => {
-                    if ![
-                        "upload",
-                        "fetch",
-                        "private",
-                        "authenticated",
-                        "sprite",
-                        "facebook",
-                        "twitter",
-                        "youtube",
-                        "vimeo",
-                    ]
-                    .contains(&s)
-                    {
-                        return Err("Invalid mode");
-                    }
+                    delivery_type = s.parse().map_err(|_| "Invalid mode")?;
                 }
                 _ => {
-                    if !public_id_territory && is_version(s) {
+                    if !public_id_territory && is_signature(s) {
+                    } else if !public_id_territory && is_version(s) {
                         public_id_territory = true;
+                        version = s[1..].parse().ok();
                     } else if !public_id_territory && is_transformation(s) {
+                        transformations.push(Transformations::Raw(s.to_string()));
+                    } else if delivery_type == DeliveryType::Fetch
+                        || K::RESOURCE_TYPE == RawKind::RESOURCE_TYPE
+                    {
+                        // Remote sources and public ids of raw files are kept as they are, including their extension.
+                        public_id_territory = true;
+                        public_id_parts.push((s, None));
                     } else if let Some((head, tail)) = s.rsplit_once('.') {
                         public_id_territory = true;
                         public_id_parts.push((head, Some(tail)));
@@ -216,17 +524,23 @@ impl TryFrom<Url> for Image {
         public_id.push_str(last.0);
         let format = last.1;
 
-        let mut image = Image::new(cloud_name.into(), public_id.into());
+        let mut asset = Asset::new(cloud_name.into(), public_id.into());
+        asset.set_delivery_type(delivery_type);
+        asset.version = version;
+        asset.query = url.query().map(Into::into);
+        asset.transformations = RefCell::new(transformations);
         if let Some(extension) = format {
-            image.set_format(extension);
+            asset.set_format(extension);
         }
 
-        Ok(image)
+        Ok(asset)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use chrono::TimeDelta;
+
     use crate::transformation::aspect_ratio::AspectRatio;
 
     use super::*;
@@ -268,6 +582,148 @@ mod tests {
         assert_eq!(image.get_format(), Some("png".into()));
     }
 
+    #[test]
+    fn mapped() {
+        let image = Image::mapped("test".into(), "remote/", "path/name.png")
+            .add_transformation(Transformations::Named("thumb".into()));
+        assert_eq!(
+            image.to_string(),
+            "https://res.cloudinary.com/test/image/upload/t_thumb/remote/path/name.png"
+        );
+    }
+
+    #[test]
+    fn page_url() {
+        let image = Image::new("test".into(), "path/name".into()).add_transformation(
+            Transformations::Resize(ResizeMode::Limit {
+                width: 800,
+                height: 800,
+            }),
+        );
+        assert_eq!(
+            image.page_url(3).as_str(),
+            "https://res.cloudinary.com/test/image/upload/pg_3/c_limit,w_800,h_800/path/name"
+        );
+        assert_eq!(
+            image.to_string(),
+            "https://res.cloudinary.com/test/image/upload/c_limit,w_800,h_800/path/name"
+        );
+    }
+
+    #[test]
+    fn sign() {
+        let image = Image::new("test".into(), "path/name".into())
+            .add_transformation(Transformations::Resize(ResizeMode::Limit {
+                width: 100,
+                height: 100,
+            }))
+            .sign("secret", SignatureAlgorithm::Sha256);
+        let url = image.build();
+        let signature = url.path_segments().unwrap().nth(3).unwrap().to_string();
+        assert!(is_signature(&signature));
+        assert_eq!(signature.len(), 37);
+        assert_eq!(
+            url.as_str(),
+            format!(
+                "https://res.cloudinary.com/test/image/upload/{}/c_limit,w_100,h_100/path/name",
+                signature
+            )
+        );
+
+        let parsed: Image = url.try_into().unwrap();
+        assert_eq!(parsed.public_id, "path/name".into());
+    }
+
+    #[test]
+    fn sign_with_auth_token() {
+        let image = Image::new("test".into(), "path/name".into())
+            .sign("secret", SignatureAlgorithm::Sha1)
+            .with_auth_token(AuthToken::new("00112233FF99", TimeDelta::minutes(5)).unwrap());
+        let url = image.build();
+        assert_eq!(url.path(), "/test/image/upload/path/name");
+        assert!(url.query().unwrap().starts_with("__cld_token__=exp="));
+    }
+
+    #[test]
+    fn delivery_type_round_trip() {
+        for url in [
+            "https://res.cloudinary.com/test/image/private/c_limit,w_100,h_100/path/name.png",
+            "https://res.cloudinary.com/test/image/fetch/c_limit,w_100,h_100/https://example.com/a/b.jpg%3Fv%3D1",
+            "https://res.cloudinary.com/test/image/facebook/c_limit,w_100,h_100/65646572251",
+        ] {
+            let image: Image = Url::parse(url).unwrap().try_into().unwrap();
+            assert_eq!(image.to_string(), url);
+        }
+    }
+
+    #[test]
+    fn fetch() {
+        let image = Image::fetch("test".into(), "https://example.com/a b.jpg");
+        assert_eq!(image.get_delivery_type(), &DeliveryType::Fetch);
+        assert_eq!(
+            image.to_string(),
+            "https://res.cloudinary.com/test/image/fetch/https://example.com/a%20b.jpg"
+        );
+    }
+
+    #[test]
+    fn video_and_raw_from_url() {
+        let video: Video =
+            Url::parse("https://res.cloudinary.com/test/video/upload/v1233456678/clips/intro.mp4")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        assert_eq!(video.public_id, "clips/intro".into());
+        assert_eq!(video.get_format(), Some("mp4".into()));
+
+        let raw: Raw = Url::parse("https://res.cloudinary.com/test/raw/upload/docs/terms.txt")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(raw.public_id, "docs/terms.txt".into());
+        assert_eq!(raw.get_format(), None);
+        assert_eq!(
+            raw.to_string(),
+            "https://res.cloudinary.com/test/raw/upload/docs/terms.txt"
+        );
+
+        let image: Result<Image, _> =
+            Url::parse("https://res.cloudinary.com/test/video/upload/clips/intro.mp4")
+                .unwrap()
+                .try_into();
+        assert_eq!(image.err(), Some("Resource type does not match"));
+    }
+
+    #[test]
+    fn round_trip() {
+        for url in [
+            "https://res.cloudinary.com/i/image/upload/c_scale,h_800,q_auto/v1233456678/path/name.jpg",
+            "https://res.cloudinary.com/i/image/upload/ar_16:9,c_fill,g_auto,w_400/e_sepia/path/with%20space/name.jpg",
+            "https://res.cloudinary.com/i/image/private/v1233456678/name",
+            "https://res.cloudinary.com/i/image/upload/c_scale,h_800/v1233456678/path/name.jpg?_a=BAMAGSRg0",
+        ] {
+            let image: Image = Url::parse(url).unwrap().try_into().unwrap();
+            assert_eq!(image.build().as_str(), url);
+        }
+    }
+
+    #[test]
+    fn version() {
+        let mut image: Image = Url::parse(
+            "https://res.cloudinary.com/i/image/upload/c_scale,h_800/v1233456678/path/name.jpg",
+        )
+        .unwrap()
+        .try_into()
+        .unwrap();
+        assert_eq!(image.get_version(), Some(1233456678));
+
+        image.set_version(1719304891);
+        assert_eq!(
+            image.to_string(),
+            "https://res.cloudinary.com/i/image/upload/c_scale,h_800/v1719304891/path/name.jpg"
+        );
+    }
+
     #[test]
     fn set_format() {
         let mut image = Image::new("test".into(), "path/name".into());
@@ -290,7 +746,7 @@ mod tests {
         image.set_format("png");
         assert_eq!(
             image.build().as_str(),
-            "https://res.cloudinary.com/i/image/upload/path/name.png"
+            "https://res.cloudinary.com/i/image/upload/c_scale,h_800,q_auto/path/name.png"
         );
     }
 
@@ -384,6 +840,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn add_limit() {
+        let image = Image::new("test".into(), "path/name".into()).add_transformation(
+            Transformations::Resize(ResizeMode::Limit {
+                width: 1920,
+                height: 1080,
+            }),
+        );
+        assert_eq!(
+            image.to_string(),
+            "https://res.cloudinary.com/test/image/upload/c_limit,w_1920,h_1080/path/name"
+        );
+    }
+
     #[test]
     fn scale_ignore_aspect_ratio() {
         let image_url: Url = Image::new("test".into(), "path/name".into())
@@ -425,7 +895,7 @@ mod tests {
         assert_eq!(image.get_format(), Some("jpg".into()));
         assert_eq!(
             image.to_string(),
-            "https://res.cloudinary.com/test/image/upload/1.2.jpg"
+            "https://res.cloudinary.com/test/image/upload/ar_0.5,foo/1.2.jpg"
         )
     }
 
@@ -442,6 +912,16 @@ mod tests {
         assert_eq!(image.get_format(), None);
     }
 
+    #[test]
+    fn named() {
+        let image = Image::new("test".into(), "path/name".into())
+            .add_transformation(Transformations::Named("thumbnail_card".into()));
+        assert_eq!(
+            image.to_string(),
+            "https://res.cloudinary.com/test/image/upload/t_thumbnail_card/path/name"
+        );
+    }
+
     #[test]
     fn pad_mode() {
         let image_url: Url = Image::new("test".into(), "path/name".into())
@@ -457,4 +937,52 @@ mod tests {
             "https://res.cloudinary.com/test/image/upload/c_pad,w_100/path/name"
         );
     }
+
+    #[test]
+    fn transformation_from_str() {
+        assert_eq!(
+            "t_thumbnail_card".parse(),
+            Ok(Transformations::Named("thumbnail_card".into()))
+        );
+        assert_eq!("pg_2".parse(), Ok(Transformations::Page(Page::Number(2))));
+        assert_eq!(
+            "c_limit,w_640,h_360".parse(),
+            Ok(Transformations::Resize(ResizeMode::Limit {
+                width: 640,
+                height: 360
+            }))
+        );
+        assert_eq!(
+            "c_scale,h_800,q_auto"
+                .parse::<Transformations>()
+                .unwrap_err()
+                .to_string(),
+            "invalid transformation parameter `q_auto`: unknown parameter"
+        );
+        assert_eq!(
+            "t_".parse::<Transformations>().unwrap_err().parameter(),
+            "t_"
+        );
+        assert_eq!(
+            "t_card,c_fill,w_10"
+                .parse::<Transformations>()
+                .unwrap_err()
+                .parameter(),
+            "t_card,c_fill,w_10"
+        );
+        assert_eq!(
+            "c_fill,w_+10"
+                .parse::<Transformations>()
+                .unwrap_err()
+                .parameter(),
+            "w_+10"
+        );
+        assert_eq!(
+            "c_crop,w_100"
+                .parse::<Transformations>()
+                .unwrap_err()
+                .parameter(),
+            "c_crop"
+        );
+    }
 }
@@ -1,7 +1,10 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
+
+use super::parse::ParseError;
+
 /// CSS named colors V4.
 /// <https://www.w3.org/TR/css-color-4/#named-colors>
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NamedColor {
     AliceBlue,
     AntiqueWhite,
@@ -307,3 +310,161 @@ impl Display for NamedColor {
         }
     }
 }
+
+impl FromStr for NamedColor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "aliceblue" => Ok(NamedColor::AliceBlue),
+            "antiquewhite" => Ok(NamedColor::AntiqueWhite),
+            "aqua" => Ok(NamedColor::Aqua),
+            "aquamarine" => Ok(NamedColor::Aquamarine),
+            "azure" => Ok(NamedColor::Azure),
+            "beige" => Ok(NamedColor::Beige),
+            "bisque" => Ok(NamedColor::Bisque),
+            "black" => Ok(NamedColor::Black),
+            "blanchedalmond" => Ok(NamedColor::BlanchedAlmond),
+            "blue" => Ok(NamedColor::Blue),
+            "blueviolet" => Ok(NamedColor::BlueViolet),
+            "brown" => Ok(NamedColor::Brown),
+            "burlywood" => Ok(NamedColor::BurlyWood),
+            "cadetblue" => Ok(NamedColor::CadetBlue),
+            "chartreuse" => Ok(NamedColor::Chartreuse),
+            "chocolate" => Ok(NamedColor::Chocolate),
+            "coral" => Ok(NamedColor::Coral),
+            "cornflowerblue" => Ok(NamedColor::CornflowerBlue),
+            "cornsilk" => Ok(NamedColor::Cornsilk),
+            "crimson" => Ok(NamedColor::Crimson),
+            "cyan" => Ok(NamedColor::Cyan),
+            "darkblue" => Ok(NamedColor::DarkBlue),
+            "darkcyan" => Ok(NamedColor::DarkCyan),
+            "darkgoldenrod" => Ok(NamedColor::DarkGoldenRod),
+            "darkgray" => Ok(NamedColor::DarkGray),
+            "darkgrey" => Ok(NamedColor::DarkGrey),
+            "darkgreen" => Ok(NamedColor::DarkGreen),
+            "darkkhaki" => Ok(NamedColor::DarkKhaki),
+            "darkmagenta" => Ok(NamedColor::DarkMagenta),
+            "darkolivegreen" => Ok(NamedColor::DarkOliveGreen),
+            "darkorange" => Ok(NamedColor::DarkOrange),
+            "darkorchid" => Ok(NamedColor::DarkOrchid),
+            "darkred" => Ok(NamedColor::DarkRed),
+            "darksalmon" => Ok(NamedColor::DarkSalmon),
+            "darkseagreen" => Ok(NamedColor::DarkSeaGreen),
+            "darkslateblue" => Ok(NamedColor::DarkSlateBlue),
+            "darkslategray" => Ok(NamedColor::DarkSlateGray),
+            "darkslategrey" => Ok(NamedColor::DarkSlateGrey),
+            "darkturquoise" => Ok(NamedColor::DarkTurquoise),
+            "darkviolet" => Ok(NamedColor::DarkViolet),
+            "deeppink" => Ok(NamedColor::DeepPink),
+            "deepskyblue" => Ok(NamedColor::DeepSkyBlue),
+            "dimgray" => Ok(NamedColor::DimGray),
+            "dimgrey" => Ok(NamedColor::DimGrey),
+            "dodgerblue" => Ok(NamedColor::DodgerBlue),
+            "firebrick" => Ok(NamedColor::FireBrick),
+            "floralwhite" => Ok(NamedColor::FloralWhite),
+            "forestgreen" => Ok(NamedColor::ForestGreen),
+            "fuchsia" => Ok(NamedColor::Fuchsia),
+            "gainsboro" => Ok(NamedColor::Gainsboro),
+            "ghostwhite" => Ok(NamedColor::GhostWhite),
+            "gold" => Ok(NamedColor::Gold),
+            "goldenrod" => Ok(NamedColor::GoldenRod),
+            "gray" => Ok(NamedColor::Gray),
+            "grey" => Ok(NamedColor::Grey),
+            "green" => Ok(NamedColor::Green),
+            "greenyellow" => Ok(NamedColor::GreenYellow),
+            "honeydew" => Ok(NamedColor::HoneyDew),
+            "hotpink" => Ok(NamedColor::HotPink),
+            "indianred" => Ok(NamedColor::IndianRed),
+            "indigo" => Ok(NamedColor::Indigo),
+            "ivory" => Ok(NamedColor::Ivory),
+            "khaki" => Ok(NamedColor::Khaki),
+            "lavender" => Ok(NamedColor::Lavender),
+            "lavenderblush" => Ok(NamedColor::LavenderBlush),
+            "lawngreen" => Ok(NamedColor::LawnGreen),
+            "lemonchiffon" => Ok(NamedColor::LemonChiffon),
+            "lightblue" => Ok(NamedColor::LightBlue),
+            "lightcoral" => Ok(NamedColor::LightCoral),
+            "lightcyan" => Ok(NamedColor::LightCyan),
+            "lightgoldenrodyellow" => Ok(NamedColor::LightGoldenRodYellow),
+            "lightgray" => Ok(NamedColor::LightGray),
+            "lightgrey" => Ok(NamedColor::LightGrey),
+            "lightgreen" => Ok(NamedColor::LightGreen),
+            "lightpink" => Ok(NamedColor::LightPink),
+            "lightsalmon" => Ok(NamedColor::LightSalmon),
+            "lightseagreen" => Ok(NamedColor::LightSeaGreen),
+            "lightskyblue" => Ok(NamedColor::LightSkyBlue),
+            "lightslategray" => Ok(NamedColor::LightSlateGray),
+            "lightslategrey" => Ok(NamedColor::LightSlateGrey),
+            "lightsteelblue" => Ok(NamedColor::LightSteelBlue),
+            "lightyellow" => Ok(NamedColor::LightYellow),
+            "lime" => Ok(NamedColor::Lime),
+            "limegreen" => Ok(NamedColor::LimeGreen),
+            "linen" => Ok(NamedColor::Linen),
+            "magenta" => Ok(NamedColor::Magenta),
+            "maroon" => Ok(NamedColor::Maroon),
+            "mediumaquamarine" => Ok(NamedColor::MediumAquaMarine),
+            "mediumblue" => Ok(NamedColor::MediumBlue),
+            "mediumorchid" => Ok(NamedColor::MediumOrchid),
+            "mediumpurple" => Ok(NamedColor::MediumPurple),
+            "mediumseagreen" => Ok(NamedColor::MediumSeaGreen),
+            "mediumslateblue" => Ok(NamedColor::MediumSlateBlue),
+            "mediumspringgreen" => Ok(NamedColor::MediumSpringGreen),
+            "mediumturquoise" => Ok(NamedColor::MediumTurquoise),
+            "mediumvioletred" => Ok(NamedColor::MediumVioletRed),
+            "midnightblue" => Ok(NamedColor::MidnightBlue),
+            "mintcream" => Ok(NamedColor::MintCream),
+            "mistyrose" => Ok(NamedColor::MistyRose),
+            "moccasin" => Ok(NamedColor::Moccasin),
+            "navajowhite" => Ok(NamedColor::NavajoWhite),
+            "navy" => Ok(NamedColor::Navy),
+            "oldlace" => Ok(NamedColor::OldLace),
+            "olive" => Ok(NamedColor::Olive),
+            "olivedrab" => Ok(NamedColor::OliveDrab),
+            "orange" => Ok(NamedColor::Orange),
+            "orangered" => Ok(NamedColor::OrangeRed),
+            "orchid" => Ok(NamedColor::Orchid),
+            "palegoldenrod" => Ok(NamedColor::PaleGoldenRod),
+            "palegreen" => Ok(NamedColor::PaleGreen),
+            "paleturquoise" => Ok(NamedColor::PaleTurquoise),
+            "palevioletred" => Ok(NamedColor::PaleVioletRed),
+            "papayawhip" => Ok(NamedColor::PapayaWhip),
+            "peachpuff" => Ok(NamedColor::PeachPuff),
+            "peru" => Ok(NamedColor::Peru),
+            "pink" => Ok(NamedColor::Pink),
+            "plum" => Ok(NamedColor::Plum),
+            "powderblue" => Ok(NamedColor::PowderBlue),
+            "purple" => Ok(NamedColor::Purple),
+            "rebeccapurple" => Ok(NamedColor::RebeccaPurple),
+            "red" => Ok(NamedColor::Red),
+            "rosybrown" => Ok(NamedColor::RosyBrown),
+            "royalblue" => Ok(NamedColor::RoyalBlue),
+            "saddlebrown" => Ok(NamedColor::SaddleBrown),
+            "salmon" => Ok(NamedColor::Salmon),
+            "sandybrown" => Ok(NamedColor::SandyBrown),
+            "seagreen" => Ok(NamedColor::SeaGreen),
+            "seashell" => Ok(NamedColor::SeaShell),
+            "sienna" => Ok(NamedColor::Sienna),
+            "silver" => Ok(NamedColor::Silver),
+            "skyblue" => Ok(NamedColor::SkyBlue),
+            "slateblue" => Ok(NamedColor::SlateBlue),
+            "slategray" => Ok(NamedColor::SlateGray),
+            "slategrey" => Ok(NamedColor::SlateGrey),
+            "snow" => Ok(NamedColor::Snow),
+            "springgreen" => Ok(NamedColor::SpringGreen),
+            "steelblue" => Ok(NamedColor::SteelBlue),
+            "tan" => Ok(NamedColor::Tan),
+            "teal" => Ok(NamedColor::Teal),
+            "thistle" => Ok(NamedColor::Thistle),
+            "tomato" => Ok(NamedColor::Tomato),
+            "turquoise" => Ok(NamedColor::Turquoise),
+            "violet" => Ok(NamedColor::Violet),
+            "wheat" => Ok(NamedColor::Wheat),
+            "white" => Ok(NamedColor::White),
+            "whitesmoke" => Ok(NamedColor::WhiteSmoke),
+            "yellow" => Ok(NamedColor::Yellow),
+            "yellowgreen" => Ok(NamedColor::YellowGreen),
+            _ => Err(ParseError::new(s, "unknown color")),
+        }
+    }
+}
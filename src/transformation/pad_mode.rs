@@ -1,13 +1,18 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
-use super::{aspect_ratio::AspectRatio, background::Background, gravity::Gravity};
+use super::{
+    aspect_ratio::AspectRatio,
+    background::Background,
+    gravity::Gravity,
+    parse::{Components, Key, ParseError},
+};
 
 /// Resizes the asset to fill the specified width and height while retaining the original aspect ratio
 /// (by default) and with all of the original asset visible. If the proportions of the original asset do not match
 /// the specified width and height, padding is added to the asset to reach the required size. You can also specify
 /// where the original asset is placed using the gravity parameter (set to center by default). Additionally, you
 /// can specify the color of the background in the case that padding is added.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum PadMode {
     PadByWidth {
         width: u32,
@@ -97,6 +102,49 @@ impl Display for PadMode {
     }
 }
 
+impl FromStr for PadMode {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let components = Components::parse(s)?;
+        if components.crop()? != "pad" {
+            return Err(components.unexpected_crop());
+        }
+        components.allow(&[
+            Key::Crop,
+            Key::Width,
+            Key::Height,
+            Key::AspectRatio,
+            Key::Gravity,
+            Key::Background,
+        ])?;
+        let ar = components.aspect_ratio()?;
+        let background = components.background()?;
+        let gravity = components.gravity()?;
+        match (components.width()?, components.height()?, ar) {
+            (Some(width), None, ar) => Ok(PadMode::PadByWidth {
+                width,
+                ar,
+                background,
+                gravity,
+            }),
+            (None, Some(height), ar) => Ok(PadMode::PadByHeight {
+                height,
+                ar,
+                background,
+                gravity,
+            }),
+            (Some(width), Some(height), None) => Ok(PadMode::Pad {
+                width,
+                height,
+                background,
+                gravity,
+            }),
+            _ => Err(components.unexpected_dimensions()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::transformation::{
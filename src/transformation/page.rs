@@ -0,0 +1,38 @@
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+use super::parse::{ParseError, number};
+
+/// Selects pages of multi-page assets like PDFs, or frames of animated images.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Page {
+    /// All pages, used by [`Upload::explode`](crate::upload::Upload::explode) to create a derived image per page.
+    All,
+    /// A single page, starting from 1.
+    Number(u32),
+}
+
+impl Display for Page {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Page::All => write!(f, "pg_all"),
+            Page::Number(page) => write!(f, "pg_{}", page),
+        }
+    }
+}
+
+impl FromStr for Page {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("pg_") {
+            Some("all") => Ok(Page::All),
+            Some(page) => number(page)
+                .map(Page::Number)
+                .ok_or_else(|| ParseError::new(s, "expected all or a page number")),
+            None => Err(ParseError::new(s, "expected a page")),
+        }
+    }
+}
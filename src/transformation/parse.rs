@@ -0,0 +1,164 @@
+use std::fmt::{Display, Formatter};
+
+use super::{aspect_ratio::AspectRatio, background::Background, gravity::Gravity};
+
+/// Error returned when a transformation string can not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    parameter: String,
+    reason: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(parameter: &str, reason: impl Into<String>) -> Self {
+        ParseError {
+            parameter: parameter.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Points the error at the whole parameter, for values that are parsed without their prefix.
+    pub(crate) fn in_parameter(mut self, parameter: &str) -> Self {
+        self.parameter = parameter.to_string();
+        self
+    }
+
+    /// The parameter that could not be parsed, e.g. `w_abc`.
+    pub fn parameter(&self) -> &str {
+        &self.parameter
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid transformation parameter `{}`: {}",
+            self.parameter, self.reason
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses an unsigned integer, rejecting the leading `+` that [`str::parse`] accepts but Display never writes.
+pub(crate) fn number(s: &str) -> Option<u32> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Key {
+    Crop,
+    Width,
+    Height,
+    AspectRatio,
+    Gravity,
+    Background,
+    Liquid,
+}
+
+/// The comma separated parameters of a single transformation, e.g. `ar_16:9,c_fill,g_auto,w_400`.
+pub(crate) struct Components<'a> {
+    source: &'a str,
+    parameters: Vec<(Key, &'a str)>,
+}
+
+impl<'a> Components<'a> {
+    pub(crate) fn parse(source: &'a str) -> Result<Self, ParseError> {
+        let mut parameters: Vec<(Key, &'a str)> = Vec::new();
+        for parameter in source.split(',') {
+            let key = match parameter {
+                "fl_ignore_aspect_ratio" => Key::AspectRatio,
+                "g_liquid" => Key::Liquid,
+                p if p.starts_with("c_") => Key::Crop,
+                p if p.starts_with("w_") => Key::Width,
+                p if p.starts_with("h_") => Key::Height,
+                p if p.starts_with("ar_") => Key::AspectRatio,
+                p if p.starts_with("g_") => Key::Gravity,
+                p if p.starts_with("b_") => Key::Background,
+                p => return Err(ParseError::new(p, "unknown parameter")),
+            };
+            if parameters.iter().any(|(k, _)| *k == key) {
+                return Err(ParseError::new(parameter, "duplicate parameter"));
+            }
+            parameters.push((key, parameter));
+        }
+        Ok(Components { source, parameters })
+    }
+
+    fn get(&self, key: Key) -> Option<&'a str> {
+        self.parameters
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, p)| *p)
+    }
+
+    /// Fails on the first parameter that is not one of `keys`.
+    pub(crate) fn allow(&self, keys: &[Key]) -> Result<(), ParseError> {
+        match self.parameters.iter().find(|(k, _)| !keys.contains(k)) {
+            Some((_, parameter)) => Err(ParseError::new(
+                parameter,
+                "not supported by this crop mode",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// The crop mode without the `c_` prefix.
+    pub(crate) fn crop(&self) -> Result<&'a str, ParseError> {
+        self.get(Key::Crop)
+            .map(|c| &c[2..])
+            .ok_or_else(|| ParseError::new(self.source, "missing crop mode"))
+    }
+
+    /// Fails on the crop mode parameter, for modes that do not match the type being parsed.
+    pub(crate) fn unexpected_crop(&self) -> ParseError {
+        ParseError::new(
+            self.get(Key::Crop).unwrap_or(self.source),
+            "unexpected crop mode",
+        )
+    }
+
+    /// Fails on the whole transformation, for combinations of width and height that do not match any variant.
+    pub(crate) fn unexpected_dimensions(&self) -> ParseError {
+        ParseError::new(
+            self.source,
+            "unexpected combination of width, height and aspect ratio",
+        )
+    }
+
+    pub(crate) fn width(&self) -> Result<Option<u32>, ParseError> {
+        self.dimension(Key::Width)
+    }
+
+    pub(crate) fn height(&self) -> Result<Option<u32>, ParseError> {
+        self.dimension(Key::Height)
+    }
+
+    fn dimension(&self, key: Key) -> Result<Option<u32>, ParseError> {
+        self.get(key)
+            .map(|p| {
+                number(&p[2..]).ok_or_else(|| ParseError::new(p, "expected a positive integer"))
+            })
+            .transpose()
+    }
+
+    pub(crate) fn aspect_ratio(&self) -> Result<Option<AspectRatio>, ParseError> {
+        self.get(Key::AspectRatio).map(str::parse).transpose()
+    }
+
+    pub(crate) fn gravity(&self) -> Result<Option<Gravity>, ParseError> {
+        self.get(Key::Gravity).map(str::parse).transpose()
+    }
+
+    pub(crate) fn background(&self) -> Result<Option<Background>, ParseError> {
+        self.get(Key::Background).map(str::parse).transpose()
+    }
+
+    pub(crate) fn liquid(&self) -> Option<()> {
+        self.get(Key::Liquid).map(|_| ())
+    }
+}
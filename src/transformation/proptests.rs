@@ -0,0 +1,382 @@
+use proptest::{option, prelude::*, sample::select};
+
+use super::{
+    CropMode, PadMode, Page, ResizeMode, Transformations,
+    aspect_ratio::AspectRatio,
+    background::{Auto, AutoModes, Background, Color, Direction, Number},
+    gravity::Gravity,
+    named_color::NamedColor,
+};
+
+fn named_color() -> impl Strategy<Value = NamedColor> {
+    select(vec![
+        NamedColor::AliceBlue,
+        NamedColor::AntiqueWhite,
+        NamedColor::Aqua,
+        NamedColor::Aquamarine,
+        NamedColor::Azure,
+        NamedColor::Beige,
+        NamedColor::Bisque,
+        NamedColor::Black,
+        NamedColor::BlanchedAlmond,
+        NamedColor::Blue,
+        NamedColor::BlueViolet,
+        NamedColor::Brown,
+        NamedColor::BurlyWood,
+        NamedColor::CadetBlue,
+        NamedColor::Chartreuse,
+        NamedColor::Chocolate,
+        NamedColor::Coral,
+        NamedColor::CornflowerBlue,
+        NamedColor::Cornsilk,
+        NamedColor::Crimson,
+        NamedColor::Cyan,
+        NamedColor::DarkBlue,
+        NamedColor::DarkCyan,
+        NamedColor::DarkGoldenRod,
+        NamedColor::DarkGray,
+        NamedColor::DarkGrey,
+        NamedColor::DarkGreen,
+        NamedColor::DarkKhaki,
+        NamedColor::DarkMagenta,
+        NamedColor::DarkOliveGreen,
+        NamedColor::DarkOrange,
+        NamedColor::DarkOrchid,
+        NamedColor::DarkRed,
+        NamedColor::DarkSalmon,
+        NamedColor::DarkSeaGreen,
+        NamedColor::DarkSlateBlue,
+        NamedColor::DarkSlateGray,
+        NamedColor::DarkSlateGrey,
+        NamedColor::DarkTurquoise,
+        NamedColor::DarkViolet,
+        NamedColor::DeepPink,
+        NamedColor::DeepSkyBlue,
+        NamedColor::DimGray,
+        NamedColor::DimGrey,
+        NamedColor::DodgerBlue,
+        NamedColor::FireBrick,
+        NamedColor::FloralWhite,
+        NamedColor::ForestGreen,
+        NamedColor::Fuchsia,
+        NamedColor::Gainsboro,
+        NamedColor::GhostWhite,
+        NamedColor::Gold,
+        NamedColor::GoldenRod,
+        NamedColor::Gray,
+        NamedColor::Grey,
+        NamedColor::Green,
+        NamedColor::GreenYellow,
+        NamedColor::HoneyDew,
+        NamedColor::HotPink,
+        NamedColor::IndianRed,
+        NamedColor::Indigo,
+        NamedColor::Ivory,
+        NamedColor::Khaki,
+        NamedColor::Lavender,
+        NamedColor::LavenderBlush,
+        NamedColor::LawnGreen,
+        NamedColor::LemonChiffon,
+        NamedColor::LightBlue,
+        NamedColor::LightCoral,
+        NamedColor::LightCyan,
+        NamedColor::LightGoldenRodYellow,
+        NamedColor::LightGray,
+        NamedColor::LightGrey,
+        NamedColor::LightGreen,
+        NamedColor::LightPink,
+        NamedColor::LightSalmon,
+        NamedColor::LightSeaGreen,
+        NamedColor::LightSkyBlue,
+        NamedColor::LightSlateGray,
+        NamedColor::LightSlateGrey,
+        NamedColor::LightSteelBlue,
+        NamedColor::LightYellow,
+        NamedColor::Lime,
+        NamedColor::LimeGreen,
+        NamedColor::Linen,
+        NamedColor::Magenta,
+        NamedColor::Maroon,
+        NamedColor::MediumAquaMarine,
+        NamedColor::MediumBlue,
+        NamedColor::MediumOrchid,
+        NamedColor::MediumPurple,
+        NamedColor::MediumSeaGreen,
+        NamedColor::MediumSlateBlue,
+        NamedColor::MediumSpringGreen,
+        NamedColor::MediumTurquoise,
+        NamedColor::MediumVioletRed,
+        NamedColor::MidnightBlue,
+        NamedColor::MintCream,
+        NamedColor::MistyRose,
+        NamedColor::Moccasin,
+        NamedColor::NavajoWhite,
+        NamedColor::Navy,
+        NamedColor::OldLace,
+        NamedColor::Olive,
+        NamedColor::OliveDrab,
+        NamedColor::Orange,
+        NamedColor::OrangeRed,
+        NamedColor::Orchid,
+        NamedColor::PaleGoldenRod,
+        NamedColor::PaleGreen,
+        NamedColor::PaleTurquoise,
+        NamedColor::PaleVioletRed,
+        NamedColor::PapayaWhip,
+        NamedColor::PeachPuff,
+        NamedColor::Peru,
+        NamedColor::Pink,
+        NamedColor::Plum,
+        NamedColor::PowderBlue,
+        NamedColor::Purple,
+        NamedColor::RebeccaPurple,
+        NamedColor::Red,
+        NamedColor::RosyBrown,
+        NamedColor::RoyalBlue,
+        NamedColor::SaddleBrown,
+        NamedColor::Salmon,
+        NamedColor::SandyBrown,
+        NamedColor::SeaGreen,
+        NamedColor::SeaShell,
+        NamedColor::Sienna,
+        NamedColor::Silver,
+        NamedColor::SkyBlue,
+        NamedColor::SlateBlue,
+        NamedColor::SlateGray,
+        NamedColor::SlateGrey,
+        NamedColor::Snow,
+        NamedColor::SpringGreen,
+        NamedColor::SteelBlue,
+        NamedColor::Tan,
+        NamedColor::Teal,
+        NamedColor::Thistle,
+        NamedColor::Tomato,
+        NamedColor::Turquoise,
+        NamedColor::Violet,
+        NamedColor::Wheat,
+        NamedColor::White,
+        NamedColor::WhiteSmoke,
+        NamedColor::Yellow,
+        NamedColor::YellowGreen,
+    ])
+}
+
+fn color() -> impl Strategy<Value = Color> {
+    prop_oneof![
+        named_color().prop_map(Color::Named),
+        any::<(u8, u8, u8)>().prop_map(|(r, g, b)| Color::RGB(r, g, b)),
+        any::<(u8, u8, u8, u8)>().prop_map(|(r, g, b, a)| Color::RGBA(r, g, b, a)),
+    ]
+}
+
+fn auto() -> impl Strategy<Value = Auto> {
+    (
+        option::of(select(vec![
+            AutoModes::Border,
+            AutoModes::Predominant,
+            AutoModes::BorderContrast,
+            AutoModes::PredominantContrast,
+            AutoModes::PredominantGradient,
+            AutoModes::PredominantGradientContrast,
+            AutoModes::BorderGradient,
+            AutoModes::BorderGradientContrast,
+        ])),
+        option::of(select(vec![Number::Two, Number::Four])),
+        option::of(select(vec![
+            Direction::Horizontal,
+            Direction::Vertical,
+            Direction::DiagonalDesc,
+            Direction::DiagonalAsc,
+        ])),
+        option::of(prop::collection::vec(color(), 0..4)),
+    )
+        .prop_map(|(mode, number, direction, palette)| Auto {
+            mode,
+            number,
+            direction,
+            palette,
+        })
+}
+
+fn background() -> impl Strategy<Value = Background> {
+    prop_oneof![
+        color().prop_map(Background::Color),
+        auto().prop_map(Background::Auto),
+    ]
+}
+
+fn gravity() -> impl Strategy<Value = Gravity> {
+    select(vec![
+        Gravity::NorthEast,
+        Gravity::North,
+        Gravity::NorthWest,
+        Gravity::West,
+        Gravity::SouthWest,
+        Gravity::South,
+        Gravity::SouthEast,
+        Gravity::East,
+        Gravity::Center,
+        Gravity::AdvEyes,
+        Gravity::AdvFace,
+        Gravity::AdvFaces,
+        Gravity::Custom,
+        Gravity::CustomFace,
+        Gravity::CustomAdvFace,
+        Gravity::CustomAdvFaces,
+        Gravity::CustomFaces,
+        Gravity::Face,
+        Gravity::FaceCenter,
+        Gravity::FaceAuto,
+        Gravity::Faces,
+        Gravity::FacesCenter,
+        Gravity::FacesAuto,
+        Gravity::OcrText,
+        Gravity::Auto,
+        Gravity::AutoSubject,
+        Gravity::AutoClassic,
+    ])
+}
+
+fn aspect_ratio() -> impl Strategy<Value = AspectRatio> {
+    prop_oneof![
+        Just(AspectRatio::Ignore),
+        any::<(u32, u32)>().prop_map(|(width, height)| AspectRatio::Sides(width, height)),
+        (0.01f32..100.0).prop_map(AspectRatio::Result),
+    ]
+}
+
+fn crop_mode() -> impl Strategy<Value = CropMode> {
+    let gravity = || option::of(gravity());
+    prop_oneof![
+        (any::<u32>(), option::of(aspect_ratio()), gravity())
+            .prop_map(|(width, ar, gravity)| CropMode::FillByWidth { width, ar, gravity }),
+        (any::<u32>(), option::of(aspect_ratio()), gravity()).prop_map(|(height, ar, gravity)| {
+            CropMode::FillByHeight {
+                height,
+                ar,
+                gravity,
+            }
+        }),
+        (any::<u32>(), any::<u32>(), gravity()).prop_map(|(width, height, gravity)| {
+            CropMode::Fill {
+                width,
+                height,
+                gravity,
+            }
+        }),
+    ]
+}
+
+fn pad_mode() -> impl Strategy<Value = PadMode> {
+    let background = || option::of(background());
+    let gravity = || option::of(gravity());
+    prop_oneof![
+        (
+            any::<u32>(),
+            option::of(aspect_ratio()),
+            background(),
+            gravity()
+        )
+            .prop_map(|(width, ar, background, gravity)| PadMode::PadByWidth {
+                width,
+                ar,
+                background,
+                gravity,
+            }),
+        (
+            any::<u32>(),
+            option::of(aspect_ratio()),
+            background(),
+            gravity()
+        )
+            .prop_map(|(height, ar, background, gravity)| PadMode::PadByHeight {
+                height,
+                ar,
+                background,
+                gravity,
+            }),
+        (any::<u32>(), any::<u32>(), background(), gravity()).prop_map(
+            |(width, height, background, gravity)| PadMode::Pad {
+                width,
+                height,
+                background,
+                gravity,
+            }
+        ),
+    ]
+}
+
+fn resize_mode() -> impl Strategy<Value = ResizeMode> {
+    let liquid = || option::of(Just(()));
+    prop_oneof![
+        (any::<u32>(), option::of(aspect_ratio()), liquid())
+            .prop_map(|(width, ar, liquid)| ResizeMode::ScaleByWidth { width, ar, liquid }),
+        (any::<u32>(), option::of(aspect_ratio()), liquid())
+            .prop_map(|(height, ar, liquid)| ResizeMode::ScaleByHeight { height, ar, liquid }),
+        (any::<u32>(), any::<u32>(), liquid()).prop_map(|(width, height, liquid)| {
+            ResizeMode::Scale {
+                width,
+                height,
+                liquid,
+            }
+        }),
+        (any::<u32>(), any::<u32>())
+            .prop_map(|(width, height)| ResizeMode::Limit { width, height }),
+    ]
+}
+
+fn transformation() -> impl Strategy<Value = Transformations> {
+    prop_oneof![
+        resize_mode().prop_map(Transformations::Resize),
+        crop_mode().prop_map(Transformations::Crop),
+        pad_mode().prop_map(Transformations::Pad),
+        "[a-z0-9_]+".prop_map(Transformations::Named),
+        prop_oneof![Just(Page::All), any::<u32>().prop_map(Page::Number)]
+            .prop_map(Transformations::Page),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn color_round_trip(color in color()) {
+        prop_assert_eq!(color.to_string().parse::<Color>(), Ok(color));
+    }
+
+    #[test]
+    fn background_round_trip(background in background()) {
+        prop_assert_eq!(background.to_string().parse::<Background>(), Ok(background));
+    }
+
+    #[test]
+    fn gravity_round_trip(gravity in gravity()) {
+        prop_assert_eq!(gravity.to_string().parse::<Gravity>(), Ok(gravity));
+    }
+
+    #[test]
+    fn aspect_ratio_round_trip(ar in aspect_ratio()) {
+        prop_assert_eq!(ar.to_string().parse::<AspectRatio>(), Ok(ar));
+    }
+
+    #[test]
+    fn crop_mode_round_trip(crop_mode in crop_mode()) {
+        prop_assert_eq!(crop_mode.to_string().parse::<CropMode>(), Ok(crop_mode));
+    }
+
+    #[test]
+    fn pad_mode_round_trip(pad_mode in pad_mode()) {
+        prop_assert_eq!(pad_mode.to_string().parse::<PadMode>(), Ok(pad_mode));
+    }
+
+    #[test]
+    fn resize_mode_round_trip(resize_mode in resize_mode()) {
+        prop_assert_eq!(resize_mode.to_string().parse::<ResizeMode>(), Ok(resize_mode));
+    }
+
+    #[test]
+    fn transformation_round_trip(transformation in transformation()) {
+        prop_assert_eq!(
+            transformation.to_string().parse::<Transformations>(),
+            Ok(transformation)
+        );
+    }
+}
@@ -1,8 +1,14 @@
-use std::fmt::{Display, Formatter};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
 
-use super::aspect_ratio::AspectRatio;
+use super::{
+    aspect_ratio::AspectRatio,
+    parse::{Components, Key, ParseError},
+};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ResizeMode {
     /// Resizes the image to the specified width and aspect ratio.
     ScaleByWidth {
@@ -30,6 +36,9 @@ pub enum ResizeMode {
         /// when changing the aspect ratio of an image.
         liquid: Option<()>,
     },
+    /// Scales the image down to fit within the specified dimensions while retaining the original aspect ratio. Images
+    /// that are already smaller are not scaled up.
+    Limit { width: u32, height: u32 },
 }
 
 impl Display for ResizeMode {
@@ -64,6 +73,51 @@ impl Display for ResizeMode {
                 height,
                 liquid.map(|_| ",g_liquid").unwrap_or("")
             ),
+            ResizeMode::Limit { width, height } => write!(f, "c_limit,w_{},h_{}", width, height),
+        }
+    }
+}
+
+impl FromStr for ResizeMode {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let components = Components::parse(s)?;
+        match components.crop()? {
+            "scale" => {
+                components.allow(&[
+                    Key::Crop,
+                    Key::Width,
+                    Key::Height,
+                    Key::AspectRatio,
+                    Key::Liquid,
+                ])?;
+                let liquid = components.liquid();
+                match (
+                    components.width()?,
+                    components.height()?,
+                    components.aspect_ratio()?,
+                ) {
+                    (Some(width), None, ar) => Ok(ResizeMode::ScaleByWidth { width, ar, liquid }),
+                    (None, Some(height), ar) => {
+                        Ok(ResizeMode::ScaleByHeight { height, ar, liquid })
+                    }
+                    (Some(width), Some(height), None) => Ok(ResizeMode::Scale {
+                        width,
+                        height,
+                        liquid,
+                    }),
+                    _ => Err(components.unexpected_dimensions()),
+                }
+            }
+            "limit" => {
+                components.allow(&[Key::Crop, Key::Width, Key::Height])?;
+                match (components.width()?, components.height()?) {
+                    (Some(width), Some(height)) => Ok(ResizeMode::Limit { width, height }),
+                    _ => Err(components.unexpected_dimensions()),
+                }
+            }
+            _ => Err(components.unexpected_crop()),
         }
     }
 }
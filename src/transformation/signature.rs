@@ -0,0 +1,94 @@
+use std::{
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
+
+use base64ct::{Base64UrlUnpadded, Encoding};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+/// Hash used for [signed delivery URLs](https://cloudinary.com/documentation/control_access_to_media#signed_delivery_urls).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureAlgorithm {
+    /// 8 characters long signatures.
+    Sha1,
+    /// 32 characters long signatures, has to be enabled for the account.
+    Sha256,
+}
+
+#[derive(Clone)]
+pub(crate) struct UrlSigner {
+    api_secret: Arc<str>,
+    algorithm: SignatureAlgorithm,
+}
+
+impl Debug for UrlSigner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UrlSigner")
+            .field("api_secret", &"***")
+            .field("algorithm", &self.algorithm)
+            .finish()
+    }
+}
+
+impl UrlSigner {
+    pub(crate) fn new(api_secret: &str, algorithm: SignatureAlgorithm) -> Self {
+        Self {
+            api_secret: api_secret.into(),
+            algorithm,
+        }
+    }
+
+    /// The `s--<signature>--` component for the part of the path following it.
+    pub(crate) fn component(&self, to_sign: &str) -> String {
+        let to_sign = format!("{}{}", to_sign, self.api_secret);
+        let signature = match self.algorithm {
+            SignatureAlgorithm::Sha1 => {
+                Base64UrlUnpadded::encode_string(&Sha1::digest(to_sign))[..8].to_string()
+            }
+            SignatureAlgorithm::Sha256 => {
+                Base64UrlUnpadded::encode_string(&Sha256::digest(to_sign))[..32].to_string()
+            }
+        };
+        format!("s--{}--", signature)
+    }
+}
+
+/// Check if the string is a signature component of a signed delivery URL.
+pub(crate) fn is_signature(s: &str) -> bool {
+    s.strip_prefix("s--")
+        .and_then(|s| s.strip_suffix("--"))
+        .is_some_and(|signature| {
+            [8, 32].contains(&signature.len())
+                && signature
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn component() {
+        assert_eq!(
+            UrlSigner::new("b", SignatureAlgorithm::Sha1).component("c_crop,h_20,w_10/image.jpg"),
+            "s--Ai4Znfl3--"
+        );
+        assert_eq!(
+            UrlSigner::new("b", SignatureAlgorithm::Sha256).component("c_crop,h_20,w_10/image.jpg"),
+            "s--w7DXgwn5wCnfEUqu7i0gnCTHxMzvnVmI--"
+        );
+    }
+
+    #[test]
+    fn signature() {
+        assert!(is_signature("s--Ai4Znfl3--"));
+        assert!(is_signature("s--2hbrSMPOjn0hJMnG1ADJJsJHdh5w6b8X--"));
+        assert!(!is_signature("s--Ai4Znfl--"));
+        assert!(!is_signature("sample"));
+    }
+}
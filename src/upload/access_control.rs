@@ -0,0 +1,55 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Serialize, Serializer};
+
+/// A single access type of
+/// [access controlled](https://cloudinary.com/documentation/control_access_to_media#access_controlled_media_assets)
+/// assets. The asset is restricted unless one of its access types is valid.
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use cloudinary::upload::AccessControl;
+///
+/// let access_control = vec![
+///     AccessControl::Token,
+///     AccessControl::Anonymous {
+///         start: Some(Utc.with_ymd_and_hms(2017, 12, 15, 12, 0, 0).unwrap()),
+///         end: None,
+///     },
+/// ];
+/// assert_eq!(
+///     serde_json::to_string(&access_control).unwrap(),
+///     r#"[{"access_type":"token"},{"access_type":"anonymous","start":"2017-12-15T12:00:00Z"}]"#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(tag = "access_type", rename_all = "lowercase")]
+pub enum AccessControl {
+    /// Requires token-based or cookie-based authentication to access the asset.
+    Token,
+    /// Allows public access to the asset, optionally only between `start` and `end`. Only one anonymous access type
+    /// can be set.
+    Anonymous {
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "serialize_datetime"
+        )]
+        start: Option<DateTime<Utc>>,
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "serialize_datetime"
+        )]
+        end: Option<DateTime<Utc>>,
+    },
+}
+
+fn serialize_datetime<S: Serializer>(
+    datetime: &Option<DateTime<Utc>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match datetime {
+        Some(datetime) => {
+            serializer.collect_str(&datetime.to_rfc3339_opts(SecondsFormat::Secs, true))
+        }
+        None => serializer.serialize_none(),
+    }
+}
@@ -0,0 +1,150 @@
+use core::fmt;
+
+use itertools::Itertools;
+
+use super::{DeliveryType, ResourceTypes};
+use crate::transformation::Transformations;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Zip,
+    Tgz,
+}
+
+impl fmt::Display for ArchiveFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArchiveFormat::Zip => write!(f, "zip"),
+            ArchiveFormat::Tgz => write!(f, "tgz"),
+        }
+    }
+}
+
+/// Selection of assets for [`Upload::create_archive`](super::Upload::create_archive) and
+/// [`Upload::download_archive_url`](super::Upload::download_archive_url). Assets matching any of the tags, public
+/// ids or prefixes are included.
+///
+/// ```rust
+/// use cloudinary::upload::{Archive, ArchiveFormat, ResourceTypes};
+///
+/// let archive = Archive {
+///     public_ids: vec!["products/shoe".into(), "products/hat".into()],
+///     flatten_folders: true,
+///     target_format: Some(ArchiveFormat::Zip),
+///     ..Archive::new(ResourceTypes::Image)
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct Archive {
+    /// [`ResourceTypes::Auto`] includes assets of all resource types.
+    pub resource_type: ResourceTypes,
+    /// Default: [`DeliveryType::Upload`].
+    pub delivery_type: Option<DeliveryType>,
+    pub tags: Vec<String>,
+    pub public_ids: Vec<String>,
+    pub prefixes: Vec<String>,
+    /// Applied to every asset before it is added to the archive.
+    pub transformations: Vec<Transformations>,
+    /// Stores all assets in the root of the archive instead of keeping their folders.
+    pub flatten_folders: bool,
+    /// Default: [`ArchiveFormat::Zip`].
+    pub target_format: Option<ArchiveFormat>,
+    /// The public id of the created archive, random when not set.
+    pub target_public_id: Option<String>,
+}
+
+pub(super) enum Param {
+    Value(String),
+    /// Sent as separate `key[]` values, signed comma separated.
+    List(Vec<String>),
+}
+
+impl Param {
+    pub(super) fn signed(&self) -> String {
+        match self {
+            Param::Value(value) => value.clone(),
+            Param::List(values) => values.join(","),
+        }
+    }
+}
+
+impl Archive {
+    pub fn new(resource_type: ResourceTypes) -> Self {
+        Self {
+            resource_type,
+            delivery_type: None,
+            tags: Vec::new(),
+            public_ids: Vec::new(),
+            prefixes: Vec::new(),
+            transformations: Vec::new(),
+            flatten_folders: false,
+            target_format: None,
+            target_public_id: None,
+        }
+    }
+
+    /// The parameters of the `generate_archive` request in the given mode, `create` or `download`.
+    pub(super) fn params(&self, mode: &str) -> Vec<(&'static str, Param)> {
+        let mut params = vec![("mode", Param::Value(mode.to_string()))];
+        for (key, values) in [
+            ("tags", &self.tags),
+            ("public_ids", &self.public_ids),
+            ("prefixes", &self.prefixes),
+        ] {
+            if !values.is_empty() {
+                params.push((key, Param::List(values.clone())));
+            }
+        }
+        if !self.transformations.is_empty() {
+            params.push((
+                "transformations",
+                Param::Value(self.transformations.iter().join("/")),
+            ));
+        }
+        if self.flatten_folders {
+            params.push(("flatten_folders", Param::Value("true".into())));
+        }
+        if let Some(target_format) = &self.target_format {
+            params.push(("target_format", Param::Value(target_format.to_string())));
+        }
+        if let Some(delivery_type) = &self.delivery_type {
+            params.push(("type", Param::Value(delivery_type.to_string())));
+        }
+        if let Some(target_public_id) = &self.target_public_id {
+            params.push(("target_public_id", Param::Value(target_public_id.clone())));
+        }
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{transformation::ResizeMode, upload::Upload};
+
+    #[test]
+    fn download_archive_url() {
+        let upload = Upload::new("key".into(), "cloud".into(), "secret".into());
+        let archive = Archive {
+            public_ids: vec!["a".into(), "b/c".into()],
+            transformations: vec![Transformations::Resize(ResizeMode::Limit {
+                width: 100,
+                height: 100,
+            })],
+            flatten_folders: true,
+            ..Archive::new(ResourceTypes::Image)
+        };
+
+        assert_eq!(
+            upload
+                .download_archive_url_at(&archive, 1719304891)
+                .as_str(),
+            "https://api.cloudinary.com/v1_1/cloud/image/generate_archive?mode=download\
+            &public_ids%5B%5D=a&public_ids%5B%5D=b%2Fc&transformations=c_limit%2Cw_100%2Ch_100\
+            &flatten_folders=true&timestamp=1719304891\
+            &signature=87464786cb5127dec4b3d4d27e42dc101ccc60c8&api_key=key"
+        );
+    }
+}
@@ -1,10 +1,28 @@
 use core::fmt;
+use std::str::FromStr;
 
+/// The storage and delivery type of an asset.
+///
+/// The Upload API, e.g. [`OptionalParameters::Type`](super::OptionalParameters::Type),
+/// [`Upload::destroy`](super::Upload::destroy) and archives, only accepts [`DeliveryType::Upload`],
+/// [`DeliveryType::Private`] and [`DeliveryType::Authenticated`]. The other variants are used in delivery URLs and to
+/// look up assets of that type with the Admin API.
+#[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DeliveryType {
     Upload,
     Private,
     Authenticated,
+    /// Remote images delivered by URL.
+    Fetch,
+    /// Sprites generated with [`Upload::generate_sprite`](super::Upload::generate_sprite).
+    Sprite,
+    /// Profile pictures of social networks, referenced by user name or id.
+    Facebook,
+    Twitter,
+    /// Thumbnails of videos, referenced by video id.
+    Youtube,
+    Vimeo,
 }
 
 impl fmt::Display for DeliveryType {
@@ -13,6 +31,31 @@ impl fmt::Display for DeliveryType {
             DeliveryType::Upload => write!(f, "upload"),
             DeliveryType::Private => write!(f, "private"),
             DeliveryType::Authenticated => write!(f, "authenticated"),
+            DeliveryType::Fetch => write!(f, "fetch"),
+            DeliveryType::Sprite => write!(f, "sprite"),
+            DeliveryType::Facebook => write!(f, "facebook"),
+            DeliveryType::Twitter => write!(f, "twitter"),
+            DeliveryType::Youtube => write!(f, "youtube"),
+            DeliveryType::Vimeo => write!(f, "vimeo"),
+        }
+    }
+}
+
+impl FromStr for DeliveryType {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "upload" => Ok(DeliveryType::Upload),
+            "private" => Ok(DeliveryType::Private),
+            "authenticated" => Ok(DeliveryType::Authenticated),
+            "fetch" => Ok(DeliveryType::Fetch),
+            "sprite" => Ok(DeliveryType::Sprite),
+            "facebook" => Ok(DeliveryType::Facebook),
+            "twitter" => Ok(DeliveryType::Twitter),
+            "youtube" => Ok(DeliveryType::Youtube),
+            "vimeo" => Ok(DeliveryType::Vimeo),
+            _ => Err("Invalid delivery type"),
         }
     }
 }
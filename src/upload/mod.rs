@@ -1,20 +1,30 @@
+mod access_control;
 mod access_mode;
 mod allowed_headers;
+mod archive;
 mod background_removal;
 mod categorizations;
 mod delivery_type;
 pub mod moderation;
+mod multi_format;
 mod options;
+mod private_download;
 mod raw_convert;
 mod resource_type;
 mod responsive_breakpoints;
 pub mod result;
+mod text;
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use chrono::Utc;
 use reqwest::multipart::{Form, Part};
 use reqwest::{Body, Client, Url};
-use result::DestroyResult;
+
+use crate::transformation::{Page, Transformations};
+use archive::Param;
+use itertools::Itertools;
+use result::{ArchiveResult, DestroyResult, ExplodeResult, MultiResult, SpriteResult, TextResult};
+use serde::de::DeserializeOwned;
 use sha1::{Digest, Sha1};
 use std::collections::BTreeSet;
 use std::path::PathBuf;
@@ -23,11 +33,21 @@ use tokio_util::codec::{BytesCodec, FramedRead};
 
 pub use self::result::UploadResult;
 pub use self::{
-    access_mode::AccessModes, allowed_headers::AllowedHeaders,
-    background_removal::BackgroundRemoval, categorizations::Categorizations,
-    delivery_type::DeliveryType, moderation::Moderation, options::OptionalParameters,
-    raw_convert::RawConvert, resource_type::ResourceTypes,
+    access_control::AccessControl,
+    access_mode::AccessModes,
+    allowed_headers::AllowedHeaders,
+    archive::{Archive, ArchiveFormat},
+    background_removal::BackgroundRemoval,
+    categorizations::Categorizations,
+    delivery_type::DeliveryType,
+    moderation::Moderation,
+    multi_format::MultiFormat,
+    options::OptionalParameters,
+    private_download::PrivateDownload,
+    raw_convert::RawConvert,
+    resource_type::ResourceTypes,
     responsive_breakpoints::ResponsiveBreakpoints,
+    text::{FontStyle, FontWeight, Text, TextDecoration},
 };
 
 pub struct Upload {
@@ -88,30 +108,43 @@ impl Upload {
         Ok(json)
     }
 
-    /// destroy the asset by public id.
+    /// Destroys the asset by public id.
+    ///
+    /// `resource_type` must be one of [`ResourceTypes::Image`], [`ResourceTypes::Video`] or [`ResourceTypes::Raw`],
+    /// [`ResourceTypes::Auto`] is rejected without sending a request.
+    /// When `invalidate` is true, cached copies of the asset are invalidated on the CDN as well.
     ///
     /// ```rust
-    /// use cloudinary::upload::{Source, Upload};
+    /// use cloudinary::upload::{DeliveryType, ResourceTypes, Upload};
     /// let upload = Upload::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string() );
-    /// let result = upload.destroy("image");
+    /// let result = upload.destroy("image", ResourceTypes::Image, DeliveryType::Upload, false);
     /// ```
-    pub async fn destroy<IS>(&self, public_id: IS) -> Result<DestroyResult>
+    pub async fn destroy<IS>(
+        &self,
+        public_id: IS,
+        resource_type: ResourceTypes,
+        delivery_type: DeliveryType,
+        invalidate: bool,
+    ) -> Result<DestroyResult>
     where
         IS: Into<String> + Clone,
     {
+        if let ResourceTypes::Auto = resource_type {
+            bail!("destroy requires an explicit resource type, got auto");
+        }
         let client = Client::new();
 
         let url = format!(
-            "https://api.cloudinary.com/v1_1/{}/image/destroy",
-            self.cloud_name
+            "https://api.cloudinary.com/v1_1/{}/{}/destroy",
+            self.cloud_name, resource_type
         );
         let response = client
             .post(&url)
-            .multipart(
-                self.build_form(&BTreeSet::from([OptionalParameters::PublicId(
-                    public_id.clone().into(),
-                )])),
-            )
+            .multipart(self.build_form(&BTreeSet::from([
+                OptionalParameters::PublicId(public_id.clone().into()),
+                OptionalParameters::Type(delivery_type),
+                OptionalParameters::Invalidate(invalidate),
+            ])))
             .send()
             .await
             .context(format!("destroy {}", public_id.into()))?;
@@ -120,39 +153,277 @@ impl Upload {
         Ok(json)
     }
 
+    /// Renders a string into an image.
+    ///
+    /// ```rust
+    /// use cloudinary::upload::{Text, Upload};
+    ///
+    /// let upload = Upload::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string() );
+    /// let text = Text::new("Spring sale", "Arial", 42);
+    /// let result = upload.text(&text);
+    /// ```
+    pub async fn text(&self, text: &Text) -> Result<TextResult> {
+        self.post_form("image/text", self.signed_form(text.params()))
+            .await
+            .context(format!("text {}", text.text))
+    }
+
+    /// Combines all images with the given tag into a single sprite image and generates the CSS and JSON files
+    /// describing the position of every image in it. `transformations` are applied to every image before it is
+    /// added to the sprite.
+    ///
+    /// ```rust
+    /// use cloudinary::transformation::{ResizeMode, Transformations};
+    /// use cloudinary::upload::Upload;
+    ///
+    /// let upload = Upload::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string() );
+    /// let transformations = [Transformations::Resize(ResizeMode::Limit { width: 64, height: 64 })];
+    /// let result = upload.generate_sprite("icons", &transformations);
+    /// ```
+    pub async fn generate_sprite(
+        &self,
+        tag: &str,
+        transformations: &[Transformations],
+    ) -> Result<SpriteResult> {
+        self.post_form(
+            "image/sprite",
+            self.signed_form(tag_params(tag, transformations)),
+        )
+        .await
+        .context(format!("sprite {}", tag))
+    }
+
+    /// Creates a single animated image, video or PDF from all images with the given tag, ordered alphabetically by
+    /// public id.
+    ///
+    /// ```rust
+    /// use cloudinary::upload::{MultiFormat, Upload};
+    ///
+    /// let upload = Upload::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string() );
+    /// let result = upload.multi("onboarding", MultiFormat::Gif, &[]);
+    /// ```
+    pub async fn multi(
+        &self,
+        tag: &str,
+        format: MultiFormat,
+        transformations: &[Transformations],
+    ) -> Result<MultiResult> {
+        let mut params = tag_params(tag, transformations);
+        params.push(("format".to_string(), format.to_string()));
+        self.post_form("image/multi", self.signed_form(params))
+            .await
+            .context(format!("multi {}", tag))
+    }
+
+    /// Creates a derived image for every page of a PDF or every frame of an animated image. The pages are created in
+    /// the background, `notification_url` is notified when all of them are ready. `transformations` are applied to
+    /// every page.
+    ///
+    /// ```rust
+    /// use cloudinary::upload::Upload;
+    ///
+    /// let upload = Upload::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string() );
+    /// let result = upload.explode("brochure", &[], None);
+    /// ```
+    pub async fn explode(
+        &self,
+        public_id: &str,
+        transformations: &[Transformations],
+        notification_url: Option<&Url>,
+    ) -> Result<ExplodeResult> {
+        let mut params = vec![
+            ("public_id".to_string(), public_id.to_string()),
+            (
+                "transformation".to_string(),
+                std::iter::once(Transformations::Page(Page::All))
+                    .chain(transformations.iter().cloned())
+                    .join("/"),
+            ),
+        ];
+        if let Some(notification_url) = notification_url {
+            params.push(("notification_url".to_string(), notification_url.to_string()));
+        }
+        self.post_form("image/explode", self.signed_form(params))
+            .await
+            .context(format!("explode {}", public_id))
+    }
+
+    /// Creates an archive of the selected assets and stores it as a raw asset.
+    ///
+    /// ```rust
+    /// use cloudinary::upload::{Archive, ResourceTypes, Upload};
+    ///
+    /// let upload = Upload::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string() );
+    /// let archive = Archive {
+    ///     tags: vec!["press_kit".into()],
+    ///     ..Archive::new(ResourceTypes::Image)
+    /// };
+    /// let result = upload.create_archive(&archive);
+    /// ```
+    pub async fn create_archive(&self, archive: &Archive) -> Result<ArchiveResult> {
+        let form = self
+            .signed_params(archive.params("create"), Utc::now().timestamp())
+            .into_iter()
+            .fold(Form::new(), |form, (key, value)| form.text(key, value));
+        self.post_form(&format!("{}/generate_archive", archive.resource_type), form)
+            .await
+            .context("create archive")
+    }
+
+    /// A signed URL that generates the archive of the selected assets on the fly when it is opened. The URL is
+    /// valid for one hour.
+    ///
+    /// ```rust
+    /// use cloudinary::upload::{Archive, ResourceTypes, Upload};
+    ///
+    /// let upload = Upload::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string() );
+    /// let archive = Archive {
+    ///     prefixes: vec!["orders/1234/".into()],
+    ///     ..Archive::new(ResourceTypes::Image)
+    /// };
+    /// let url = upload.download_archive_url(&archive);
+    /// ```
+    pub fn download_archive_url(&self, archive: &Archive) -> Url {
+        self.download_archive_url_at(archive, Utc::now().timestamp())
+    }
+
+    fn download_archive_url_at(&self, archive: &Archive, timestamp: i64) -> Url {
+        self.signed_url(
+            &format!("{}/generate_archive", archive.resource_type),
+            archive.params("download"),
+            timestamp,
+        )
+    }
+
+    /// A signed URL downloading a private or authenticated asset. Works without a network call, the URL expires at
+    /// [`PrivateDownload::expires_at`].
+    ///
+    /// ```rust
+    /// use cloudinary::upload::{PrivateDownload, ResourceTypes, Upload};
+    ///
+    /// let upload = Upload::new("api_key".to_string(), "cloud_name".to_string(), "api_secret".to_string() );
+    /// let download = PrivateDownload::new(ResourceTypes::Image, "invoices/1234", "pdf");
+    /// let url = upload.private_download_url(&download);
+    /// ```
+    pub fn private_download_url(&self, download: &PrivateDownload) -> Url {
+        self.private_download_url_at(download, Utc::now().timestamp())
+    }
+
+    fn private_download_url_at(&self, download: &PrivateDownload, timestamp: i64) -> Url {
+        self.signed_url(
+            &format!("{}/download", download.resource_type),
+            download.params(),
+            timestamp,
+        )
+    }
+
+    fn signed_url(&self, action: &str, params: Vec<(&'static str, Param)>, timestamp: i64) -> Url {
+        let mut url = Url::parse(&format!(
+            "https://api.cloudinary.com/v1_1/{}/{}",
+            self.cloud_name, action
+        ))
+        .unwrap();
+        url.query_pairs_mut()
+            .extend_pairs(self.signed_params(params, timestamp));
+        url
+    }
+
+    /// Parameters of a request including the signature. Lists are signed comma separated but sent as separate
+    /// `key[]` values.
+    fn signed_params(
+        &self,
+        params: Vec<(&'static str, Param)>,
+        timestamp: i64,
+    ) -> Vec<(String, String)> {
+        let mut parts: Vec<String> = params
+            .iter()
+            .map(|(key, param)| format!("{}={}", key, param.signed()))
+            .collect();
+        parts.push(format!("timestamp={}", timestamp));
+        let signature = self.signature(parts);
+
+        let mut pairs = Vec::new();
+        for (key, param) in params {
+            match param {
+                Param::Value(value) => pairs.push((key.to_string(), value)),
+                Param::List(values) => pairs.extend(
+                    values
+                        .into_iter()
+                        .map(|value| (format!("{}[]", key), value)),
+                ),
+            }
+        }
+        pairs.push(("timestamp".to_string(), timestamp.to_string()));
+        pairs.push(("signature".to_string(), signature));
+        pairs.push(("api_key".to_string(), self.api_key.clone()));
+        pairs
+    }
+
+    async fn post_form<T: DeserializeOwned>(&self, action: &str, form: Form) -> Result<T> {
+        let url = format!(
+            "https://api.cloudinary.com/v1_1/{}/{}",
+            self.cloud_name, action
+        );
+        let response = Client::new()
+            .post(&url)
+            .multipart(form)
+            .send()
+            .await
+            .context(format!("post to {}", url))?;
+        let text = response.text().await?;
+        let json = serde_json::from_str(&text).context(format!("failed to parse:\n\n {}", text))?;
+        Ok(json)
+    }
+
     /// Composes the request body for a multipart/form-data request.
     ///
     /// [`OptionalParameters`] are sorted alphabetically to comply with Cloudinary's authentication signature requirements.
     /// See: <https://cloudinary.com/documentation/authentication_signatures>
     fn build_form(&self, options: &BTreeSet<OptionalParameters>) -> Form {
+        self.signed_form(options.iter().map(OptionalParameters::get_pair))
+    }
+
+    /// Composes a signed multipart/form-data request body from key value pairs.
+    fn signed_form<I: IntoIterator<Item = (String, String)>>(&self, params: I) -> Form {
         let mut form = Form::new();
-        let mut hasher = Sha1::new();
         let timestamp = Utc::now().timestamp_millis().to_string();
         let mut parts: Vec<String> = Vec::new();
 
-        for option in options {
-            let (key, value) = option.get_pair();
+        for (key, value) in params {
             if key != "resource_type" {
-                parts.push(option.to_string());
+                parts.push(format!("{}={}", key, value));
             };
 
             form = form.text(key, value);
         }
 
         parts.push(format!("timestamp={}", timestamp));
-        parts.sort();
 
-        let params_string = format!("{}{}", parts.join("&"), self.api_secret);
-        hasher.update(&params_string);
-
-        form = form.text(
-            "signature",
-            base16ct::lower::encode_string(&hasher.finalize()),
-        );
+        form = form.text("signature", self.signature(parts));
         form = form.text("api_key", self.api_key.clone());
         form = form.text("timestamp", timestamp.clone());
         form
     }
+
+    /// Signs `key=value` parts, see <https://cloudinary.com/documentation/authentication_signatures>. The parts are
+    /// sorted before signing.
+    fn signature(&self, mut parts: Vec<String>) -> String {
+        parts.sort();
+        let mut hasher = Sha1::new();
+        hasher.update(format!("{}{}", parts.join("&"), self.api_secret));
+        base16ct::lower::encode_string(&hasher.finalize())
+    }
+}
+
+fn tag_params(tag: &str, transformations: &[Transformations]) -> Vec<(String, String)> {
+    let mut params = vec![("tag".to_string(), tag.to_string())];
+    if !transformations.is_empty() {
+        params.push((
+            "transformation".to_string(),
+            transformations.iter().join("/"),
+        ));
+    }
+    params
 }
 
 async fn prepare_file(src: &PathBuf) -> Result<Part> {
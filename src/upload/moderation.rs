@@ -43,6 +43,21 @@ pub enum Moderation {
     GoogleVideoModeration,
 }
 
+impl Moderation {
+    /// The moderation kind without parameters, as used to list the moderation queue.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Moderation::Manual => "manual",
+            Moderation::PerceptionPoint => "perception_point",
+            Moderation::Webpurify => "webpurify",
+            Moderation::AwsRek => "aws_rek",
+            Moderation::Duplicate(_) => "duplicate",
+            Moderation::AwsRekVideo => "aws_rek_video",
+            Moderation::GoogleVideoModeration => "google_video_moderation",
+        }
+    }
+}
+
 impl Display for Moderation {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
@@ -70,14 +85,6 @@ impl Eq for Moderation {}
 
 impl Hash for Moderation {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        match self {
-            Moderation::Manual => "manual".hash(state),
-            Moderation::PerceptionPoint => "perception_point".hash(state),
-            Moderation::Webpurify => "webpurify".hash(state),
-            Moderation::AwsRek => "aws_rek".hash(state),
-            Moderation::Duplicate(_) => "duplicate".hash(state),
-            Moderation::AwsRekVideo => "aws_rek_video".hash(state),
-            Moderation::GoogleVideoModeration => "google_video_moderation".hash(state),
-        }
+        self.kind().hash(state)
     }
 }
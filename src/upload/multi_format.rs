@@ -0,0 +1,26 @@
+use core::fmt;
+
+/// Output formats of [`Upload::multi`](super::Upload::multi).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MultiFormat {
+    /// Animated GIF.
+    Gif,
+    /// Animated WebP.
+    Webp,
+    /// A PDF with one page per image.
+    Pdf,
+    Mp4,
+    Webm,
+}
+
+impl fmt::Display for MultiFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MultiFormat::Gif => write!(f, "gif"),
+            MultiFormat::Webp => write!(f, "webp"),
+            MultiFormat::Pdf => write!(f, "pdf"),
+            MultiFormat::Mp4 => write!(f, "mp4"),
+            MultiFormat::Webm => write!(f, "webm"),
+        }
+    }
+}
@@ -9,7 +9,7 @@ use crate::transformation::Transformations;
 
 use super::raw_convert::RawConvert;
 use super::{
-    access_mode::AccessModes, allowed_headers::AllowedHeaders,
+    access_control::AccessControl, access_mode::AccessModes, allowed_headers::AllowedHeaders,
     background_removal::BackgroundRemoval, categorizations::Categorizations,
     delivery_type::DeliveryType, moderation::Moderation, resource_type::ResourceTypes,
     responsive_breakpoints::ResponsiveBreakpoints,
@@ -151,20 +151,14 @@ pub enum OptionalParameters {
     ///
     /// Default: upload.
     Type(DeliveryType),
-    /// TODO: better type.
-    ///
-    /// Restrict access to the asset by passing an array of access types for the asset. The asset is restricted unless
+    /// Restrict access to the asset by passing a list of access types for the asset. The asset is restricted unless
     /// one of the access types is valid.
     ///
-    /// Possible values for each access type:
-    ///
-    /// - token requires either Token-based access or Cookie-based access for accessing the asset.
-    ///   For example: access_type: "token"
-    /// - anonymous allows public access to the asset. The anonymous access type can optionally include start and/or
-    ///   end dates (in ISO 8601 format) that define when the asset is publicly available. Note that you can only
-    ///   include a single 'anonymous' access type.
-    ///   For example: `access_type: "anonymous", start: "2017-12-15T12:00Z", end: "2018-01-20T12:00Z"`
-    AccessControl(String),
+    /// - [`AccessControl::Token`] requires either Token-based access or Cookie-based access for accessing the asset.
+    /// - [`AccessControl::Anonymous`] allows public access to the asset. The anonymous access type can optionally
+    ///   include start and/or end dates that define when the asset is publicly available. Note that you can only
+    ///   include a single anonymous access type.
+    AccessControl(Vec<AccessControl>),
     /// Allows the asset to behave as if it's of the authenticated 'type' (see above) while still using the default
     /// 'upload' type in delivery URLs. The asset can later be made public by changing its access_mode via the
     /// [Admin API](https://cloudinary.com/documentation/admin_api#update_access_mode), without having to update any
@@ -555,7 +549,10 @@ impl OptionalParameters {
             }
             OptionalParameters::ResourceType(s) => ("resource_type".to_string(), s.to_string()),
             OptionalParameters::Type(e) => ("type".to_string(), e.to_string()),
-            OptionalParameters::AccessControl(s) => ("access_control".to_string(), s.to_string()),
+            OptionalParameters::AccessControl(value) => (
+                "access_control".to_string(),
+                serde_json::to_string(value).unwrap(),
+            ),
             OptionalParameters::AccessMode(s) => ("access_mode".to_string(), s.to_string()),
             OptionalParameters::DiscardOriginalFilename(b) => {
                 ("discard_original_filename".to_string(), b.to_string())
@@ -705,6 +702,7 @@ mod tests {
         str::FromStr,
     };
 
+    use chrono::{TimeZone, Utc};
     use itertools::Itertools;
     use pretty_assertions::assert_eq;
     use url::Url;
@@ -712,8 +710,9 @@ mod tests {
     use crate::{
         transformation::{Transformations, crop_mode::CropMode, pad_mode::PadMode},
         upload::{
-            AccessModes, AllowedHeaders, BackgroundRemoval, Categorizations, DeliveryType,
-            Moderation, OptionalParameters, RawConvert, ResourceTypes, ResponsiveBreakpoints,
+            AccessControl, AccessModes, AllowedHeaders, BackgroundRemoval, Categorizations,
+            DeliveryType, Moderation, OptionalParameters, RawConvert, ResourceTypes,
+            ResponsiveBreakpoints,
         },
     };
 
@@ -842,8 +841,19 @@ mod tests {
     #[test]
     fn access_control() {
         assert_eq!(
-            OptionalParameters::AccessControl("control".to_string()).get_pair(),
-            ("access_control".to_string(), "control".to_string())
+            OptionalParameters::AccessControl(vec![
+                AccessControl::Token,
+                AccessControl::Anonymous {
+                    start: Some(Utc.with_ymd_and_hms(2017, 12, 15, 12, 0, 0).unwrap()),
+                    end: Some(Utc.with_ymd_and_hms(2018, 1, 20, 12, 0, 0).unwrap()),
+                },
+            ])
+            .get_pair(),
+            (
+                "access_control".to_string(),
+                r#"[{"access_type":"token"},{"access_type":"anonymous","start":"2017-12-15T12:00:00Z","end":"2018-01-20T12:00:00Z"}]"#
+                    .to_string()
+            )
         );
     }
 
@@ -0,0 +1,105 @@
+use chrono::{DateTime, Utc};
+
+use super::{DeliveryType, ResourceTypes, archive::Param};
+
+/// A download of a [`DeliveryType::Private`] or [`DeliveryType::Authenticated`] asset, see
+/// [`Upload::private_download_url`](super::Upload::private_download_url).
+///
+/// ```rust
+/// use chrono::{Duration, Utc};
+/// use cloudinary::upload::{DeliveryType, PrivateDownload, ResourceTypes};
+///
+/// let download = PrivateDownload {
+///     delivery_type: Some(DeliveryType::Authenticated),
+///     expires_at: Some(Utc::now() + Duration::minutes(10)),
+///     attachment: true,
+///     ..PrivateDownload::new(ResourceTypes::Image, "invoices/1234", "pdf")
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrivateDownload {
+    pub resource_type: ResourceTypes,
+    pub public_id: String,
+    /// The format to deliver the asset in, e.g. `jpg`. Converts the asset if it was uploaded in another format.
+    pub format: String,
+    /// Default: [`DeliveryType::Upload`].
+    pub delivery_type: Option<DeliveryType>,
+    /// Default: one hour from the time the URL was generated.
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Delivers the asset as an attachment instead of displaying it in the browser.
+    pub attachment: bool,
+}
+
+impl PrivateDownload {
+    pub fn new<P: Into<String>, F: Into<String>>(
+        resource_type: ResourceTypes,
+        public_id: P,
+        format: F,
+    ) -> Self {
+        Self {
+            resource_type,
+            public_id: public_id.into(),
+            format: format.into(),
+            delivery_type: None,
+            expires_at: None,
+            attachment: false,
+        }
+    }
+
+    pub(super) fn params(&self) -> Vec<(&'static str, Param)> {
+        let mut params = vec![
+            ("public_id", Param::Value(self.public_id.clone())),
+            ("format", Param::Value(self.format.clone())),
+        ];
+        if let Some(delivery_type) = &self.delivery_type {
+            params.push(("type", Param::Value(delivery_type.to_string())));
+        }
+        if let Some(expires_at) = &self.expires_at {
+            params.push((
+                "expires_at",
+                Param::Value(expires_at.timestamp().to_string()),
+            ));
+        }
+        if self.attachment {
+            params.push(("attachment", Param::Value("true".into())));
+        }
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::upload::Upload;
+
+    #[test]
+    fn private_download_url() {
+        let upload = Upload::new("key".into(), "cloud".into(), "secret".into());
+        let download = PrivateDownload {
+            delivery_type: Some(DeliveryType::Private),
+            expires_at: Some(Utc.with_ymd_and_hms(2024, 6, 25, 10, 0, 0).unwrap()),
+            attachment: true,
+            ..PrivateDownload::new(ResourceTypes::Image, "invoices/1234", "pdf")
+        };
+
+        assert_eq!(
+            upload
+                .private_download_url_at(&download, 1719304891)
+                .as_str(),
+            "https://api.cloudinary.com/v1_1/cloud/image/download?public_id=invoices%2F1234\
+            &format=pdf&type=private&expires_at=1719309600&attachment=true&timestamp=1719304891\
+            &signature=f7bc9f1362a2f0d62e4ff785b4c7b89b4c85180a&api_key=key"
+        );
+    }
+
+    #[test]
+    fn new_with_mixed_arguments() {
+        let id = 1234;
+        let download = PrivateDownload::new(ResourceTypes::Image, format!("invoices/{id}"), "pdf");
+        assert_eq!(download.public_id, "invoices/1234");
+        assert_eq!(download.format, "pdf");
+    }
+}
@@ -1,8 +1,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Deserializer, de};
-use std::{fmt::Display, str::FromStr};
+use std::{collections::HashMap, fmt::Display, str::FromStr};
 
-fn deserialize_from_str<'de, S, D>(deserializer: D) -> Result<S, D::Error>
+pub(crate) fn deserialize_from_str<'de, S, D>(deserializer: D) -> Result<S, D::Error>
 where
     S: FromStr,      // Required for S::from_str...
     S::Err: Display, // Required for .map_err(de::Error::custom)
@@ -32,6 +32,110 @@ pub enum UploadResult {
     Error(Box<Error>),
 }
 
+#[derive(Clone, Deserialize, Debug)]
+#[serde(untagged)]
+pub enum TextResult {
+    Response(Box<TextResponse>),
+    Error(Box<Error>),
+}
+
+/// <https://cloudinary.com/documentation/image_upload_api_reference#text>
+#[derive(Clone, Deserialize, Debug)]
+pub struct TextResponse {
+    pub public_id: String,
+    pub version: usize,
+    pub signature: Option<String>,
+    pub width: usize,
+    pub height: usize,
+    pub format: Option<String>,
+    pub url: String,
+    pub secure_url: String,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+#[serde(untagged)]
+pub enum SpriteResult {
+    Response(Box<SpriteResponse>),
+    Error(Box<Error>),
+}
+
+/// Position of a single image in a sprite.
+#[derive(Clone, Deserialize, Debug, PartialEq, Eq)]
+pub struct SpriteImageInfo {
+    pub width: usize,
+    pub height: usize,
+    pub x: usize,
+    pub y: usize,
+}
+
+/// <https://cloudinary.com/documentation/image_upload_api_reference#sprite>
+#[derive(Clone, Deserialize, Debug)]
+pub struct SpriteResponse {
+    pub public_id: String,
+    pub version: usize,
+    pub css_url: String,
+    pub secure_css_url: String,
+    pub image_url: String,
+    pub secure_image_url: String,
+    pub json_url: String,
+    pub secure_json_url: String,
+    /// Keyed by the public id of every image in the sprite.
+    #[serde(default)]
+    pub image_infos: HashMap<String, SpriteImageInfo>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+#[serde(untagged)]
+pub enum MultiResult {
+    Response(Box<MultiResponse>),
+    Error(Box<Error>),
+}
+
+/// <https://cloudinary.com/documentation/image_upload_api_reference#multi>
+#[derive(Clone, Deserialize, Debug)]
+pub struct MultiResponse {
+    pub asset_id: Option<String>,
+    pub public_id: String,
+    pub version: usize,
+    pub url: String,
+    pub secure_url: String,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+#[serde(untagged)]
+pub enum ExplodeResult {
+    Response(Box<ExplodeResponse>),
+    Error(Box<Error>),
+}
+
+/// <https://cloudinary.com/documentation/image_upload_api_reference#explode>
+#[derive(Clone, Deserialize, Debug)]
+pub struct ExplodeResponse {
+    /// `processing` until the notification is sent.
+    pub status: String,
+    pub batch_id: String,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+#[serde(untagged)]
+pub enum ArchiveResult {
+    Response(Box<ArchiveResponse>),
+    Error(Box<Error>),
+}
+
+/// <https://cloudinary.com/documentation/image_upload_api_reference#generate_archive>
+#[derive(Clone, Deserialize, Debug)]
+pub struct ArchiveResponse {
+    pub asset_id: Option<String>,
+    pub public_id: String,
+    pub version: Option<usize>,
+    pub url: String,
+    pub secure_url: String,
+    pub bytes: usize,
+    pub file_count: usize,
+    pub resource_count: usize,
+}
+
 #[derive(Clone, Deserialize, Debug)]
 pub struct Response {
     pub asset_id: String,
@@ -106,7 +210,131 @@ pub struct ResponseWithImageMetadata {
     pub api_key: String,
 }
 
-#[derive(Clone, Deserialize, Debug)]
-pub struct DestroyResult {
-    pub result: String,
+/// <https://cloudinary.com/documentation/image_upload_api_reference#destroy_response>
+#[derive(Clone, Deserialize, Debug, PartialEq, Eq)]
+#[serde(tag = "result")]
+pub enum DestroyResult {
+    #[serde(rename = "ok")]
+    Ok,
+    #[serde(rename = "not found")]
+    NotFound,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ArchiveResult, DestroyResult, ExplodeResult, MultiResult, SpriteImageInfo, SpriteResult,
+        TextResult,
+    };
+
+    #[test]
+    fn destroy_result() {
+        assert_eq!(
+            serde_json::from_str::<DestroyResult>(r#"{"result":"ok"}"#).unwrap(),
+            DestroyResult::Ok
+        );
+        assert_eq!(
+            serde_json::from_str::<DestroyResult>(r#"{"result":"not found"}"#).unwrap(),
+            DestroyResult::NotFound
+        );
+    }
+
+    #[test]
+    fn text_result() {
+        let result: TextResult = serde_json::from_str(
+            r#"{
+                "public_id": "dyrcufp6uezgw4mrrrjx",
+                "version": 1719304891,
+                "signature": "3bd5d5f6f9a0b2c5a8f1a3e5b4c1d6e7f8a9b0c1",
+                "width": 230,
+                "height": 53,
+                "url": "http://res.cloudinary.com/demo/image/text/v1719304891/dyrcufp6uezgw4mrrrjx.png",
+                "secure_url": "https://res.cloudinary.com/demo/image/text/v1719304891/dyrcufp6uezgw4mrrrjx.png"
+            }"#,
+        )
+        .unwrap();
+        assert!(matches!(result, TextResult::Response(response) if response.width == 230));
+
+        let result: TextResult =
+            serde_json::from_str(r#"{"error": {"message": "Missing required parameter - text"}}"#)
+                .unwrap();
+        assert!(matches!(result, TextResult::Error(_)));
+    }
+
+    #[test]
+    fn sprite_result() {
+        let result: SpriteResult = serde_json::from_str(
+            r#"{
+                "css_url": "http://res.cloudinary.com/demo/image/sprite/v1719304891/icons.css",
+                "image_url": "http://res.cloudinary.com/demo/image/sprite/v1719304891/icons.png",
+                "secure_css_url": "https://res.cloudinary.com/demo/image/sprite/v1719304891/icons.css",
+                "secure_image_url": "https://res.cloudinary.com/demo/image/sprite/v1719304891/icons.png",
+                "json_url": "http://res.cloudinary.com/demo/image/sprite/v1719304891/icons.json",
+                "secure_json_url": "https://res.cloudinary.com/demo/image/sprite/v1719304891/icons.json",
+                "version": 1719304891,
+                "public_id": "icons",
+                "image_infos": {
+                    "home": {"width": 64, "height": 64, "x": 0, "y": 0}
+                }
+            }"#,
+        )
+        .unwrap();
+        let SpriteResult::Response(response) = result else {
+            panic!("expected a response");
+        };
+        assert_eq!(
+            response.image_infos["home"],
+            SpriteImageInfo {
+                width: 64,
+                height: 64,
+                x: 0,
+                y: 0
+            }
+        );
+    }
+
+    #[test]
+    fn multi_result() {
+        let result: MultiResult = serde_json::from_str(
+            r#"{
+                "url": "http://res.cloudinary.com/demo/image/multi/v1719304891/onboarding.gif",
+                "secure_url": "https://res.cloudinary.com/demo/image/multi/v1719304891/onboarding.gif",
+                "asset_id": "19a2b3c4",
+                "public_id": "onboarding",
+                "version": 1719304891
+            }"#,
+        )
+        .unwrap();
+        assert!(
+            matches!(result, MultiResult::Response(response) if response.public_id == "onboarding")
+        );
+    }
+
+    #[test]
+    fn explode_result() {
+        let result: ExplodeResult = serde_json::from_str(
+            r#"{"status": "processing", "batch_id": "6fb0e1ea18c2ca6bc6d8ebdb2b5d0d9a"}"#,
+        )
+        .unwrap();
+        assert!(
+            matches!(result, ExplodeResult::Response(response) if response.status == "processing")
+        );
+    }
+
+    #[test]
+    fn archive_result() {
+        let result: ArchiveResult = serde_json::from_str(
+            r#"{
+                "url": "http://res.cloudinary.com/demo/raw/upload/v1719304891/press_kit.zip",
+                "secure_url": "https://res.cloudinary.com/demo/raw/upload/v1719304891/press_kit.zip",
+                "public_id": "press_kit.zip",
+                "version": 1719304891,
+                "bytes": 1240538,
+                "file_count": 12,
+                "resource_count": 12
+            }"#,
+        )
+        .unwrap();
+        assert!(matches!(result, ArchiveResult::Response(response) if response.file_count == 12));
+    }
 }
@@ -0,0 +1,175 @@
+use std::fmt::{Display, Formatter};
+
+use crate::transformation::background::Color;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FontWeight {
+    Normal,
+    Bold,
+}
+
+impl Display for FontWeight {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FontWeight::Normal => write!(f, "normal"),
+            FontWeight::Bold => write!(f, "bold"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FontStyle {
+    Normal,
+    Italic,
+}
+
+impl Display for FontStyle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FontStyle::Normal => write!(f, "normal"),
+            FontStyle::Italic => write!(f, "italic"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextDecoration {
+    None,
+    Underline,
+    Strikethrough,
+}
+
+impl Display for TextDecoration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TextDecoration::None => write!(f, "none"),
+            TextDecoration::Underline => write!(f, "underline"),
+            TextDecoration::Strikethrough => write!(f, "strikethrough"),
+        }
+    }
+}
+
+/// Parameters of [`Upload::text`](super::Upload::text), which renders a string into an image.
+///
+/// ```rust
+/// use cloudinary::transformation::{background::Color, named_color::NamedColor};
+/// use cloudinary::upload::{FontWeight, Text};
+///
+/// let text = Text {
+///     font_color: Some(Color::RGB(255, 255, 255)),
+///     font_weight: Some(FontWeight::Bold),
+///     background: Some(NamedColor::Black.into()),
+///     ..Text::new("Spring sale", "Arial", 42)
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct Text {
+    pub text: String,
+    /// Any universally available font or a custom font uploaded as a raw asset.
+    pub font_family: String,
+    /// Font size in points.
+    pub font_size: u32,
+    pub font_color: Option<Color>,
+    pub font_weight: Option<FontWeight>,
+    pub font_style: Option<FontStyle>,
+    /// Transparent when not set.
+    pub background: Option<Color>,
+    /// Opacity of the text, between 0 and 100.
+    pub opacity: Option<u8>,
+    pub text_decoration: Option<TextDecoration>,
+    /// Random when not set.
+    pub public_id: Option<String>,
+}
+
+impl Text {
+    pub fn new<T: Into<String>, F: Into<String>>(text: T, font_family: F, font_size: u32) -> Self {
+        Self {
+            text: text.into(),
+            font_family: font_family.into(),
+            font_size,
+            font_color: None,
+            font_weight: None,
+            font_style: None,
+            background: None,
+            opacity: None,
+            text_decoration: None,
+            public_id: None,
+        }
+    }
+
+    /// The parameters as they are sent to the API.
+    pub(super) fn params(&self) -> Vec<(String, String)> {
+        let mut params = vec![
+            ("text".to_string(), self.text.clone()),
+            ("font_family".to_string(), self.font_family.clone()),
+            ("font_size".to_string(), self.font_size.to_string()),
+        ];
+        let optional = [
+            ("font_color", self.font_color.as_ref().map(css_color)),
+            (
+                "font_weight",
+                self.font_weight.as_ref().map(|w| w.to_string()),
+            ),
+            (
+                "font_style",
+                self.font_style.as_ref().map(|s| s.to_string()),
+            ),
+            ("background", self.background.as_ref().map(css_color)),
+            ("opacity", self.opacity.map(|o| o.to_string())),
+            (
+                "text_decoration",
+                self.text_decoration.as_ref().map(|d| d.to_string()),
+            ),
+            ("public_id", self.public_id.clone()),
+        ];
+        params.extend(
+            optional
+                .into_iter()
+                .filter_map(|(key, value)| value.map(|value| (key.to_string(), value))),
+        );
+        params
+    }
+}
+
+/// Unlike transformations, the text API expects colors in CSS notation.
+fn css_color(color: &Color) -> String {
+    match color {
+        Color::Named(color) => color.to_string(),
+        Color::RGB(r, g, b) => format!("#{:02x}{:02x}{:02x}", r, g, b),
+        Color::RGBA(r, g, b, a) => format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::transformation::named_color::NamedColor;
+
+    #[test]
+    fn params() {
+        let text = Text {
+            font_color: Some(Color::RGB(255, 0, 10)),
+            font_style: Some(FontStyle::Italic),
+            background: Some(NamedColor::Black.into()),
+            opacity: Some(80),
+            text_decoration: Some(TextDecoration::Underline),
+            ..Text::new(String::from("Hello"), "Arial", 12)
+        };
+
+        assert_eq!(
+            text.params(),
+            vec![
+                ("text".to_string(), "Hello".to_string()),
+                ("font_family".to_string(), "Arial".to_string()),
+                ("font_size".to_string(), "12".to_string()),
+                ("font_color".to_string(), "#ff000a".to_string()),
+                ("font_style".to_string(), "italic".to_string()),
+                ("background".to_string(), "black".to_string()),
+                ("opacity".to_string(), "80".to_string()),
+                ("text_decoration".to_string(), "underline".to_string()),
+            ]
+        );
+    }
+}